@@ -0,0 +1,106 @@
+use axum::extract::FromRequestParts;
+use axum::http::HeaderMap;
+use axum::http::header::ACCEPT;
+use axum::http::request::Parts;
+use std::convert::Infallible;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct MediaRange {
+    pub media_type: String,
+    pub quality: f32,
+}
+
+impl MediaRange {
+    fn specificity(&self, media_type: &str) -> Option<u8> {
+        let (range_type, range_subtype) = self.media_type.split_once('/')?;
+        let (media_type, media_subtype) = media_type.split_once('/')?;
+        match (range_type, range_subtype) {
+            ("*", "*") => Some(0),
+            (t, "*") if t.eq_ignore_ascii_case(media_type) => Some(1),
+            (t, s)
+                if t.eq_ignore_ascii_case(media_type) && s.eq_ignore_ascii_case(media_subtype) =>
+            {
+                Some(2)
+            }
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Accept(pub Vec<MediaRange>);
+
+impl Default for Accept {
+    fn default() -> Self {
+        Self(vec![MediaRange {
+            media_type: "*/*".into(),
+            quality: 1.0,
+        }])
+    }
+}
+
+impl Accept {
+    pub fn parse(header: &str) -> Self {
+        let ranges = header
+            .split(',')
+            .filter_map(|range| {
+                let mut params = range.split(';').map(str::trim);
+                let media_type = params.next().filter(|m| m.contains('/'))?;
+                let quality = params
+                    .filter_map(|param| param.split_once('='))
+                    .find(|(key, _)| key.trim().eq_ignore_ascii_case("q"))
+                    .and_then(|(_, q)| q.trim().parse::<f32>().ok())
+                    .unwrap_or(1.0)
+                    .clamp(0.0, 1.0);
+                Some(MediaRange {
+                    media_type: media_type.to_ascii_lowercase(),
+                    quality,
+                })
+            })
+            .collect();
+        Self(ranges)
+    }
+
+    pub fn from_headers(headers: &HeaderMap) -> Self {
+        let values: Vec<_> = headers
+            .get_all(ACCEPT)
+            .iter()
+            .filter_map(|value| value.to_str().ok())
+            .collect();
+        if values.is_empty() {
+            Self::default()
+        } else {
+            Self::parse(&values.join(","))
+        }
+    }
+
+    pub fn quality(&self, media_type: &str) -> f32 {
+        self.0
+            .iter()
+            .filter_map(|range| Some((range.specificity(media_type)?, range.quality)))
+            .max_by_key(|(specificity, _)| *specificity)
+            .map_or(0.0, |(_, quality)| quality)
+    }
+
+    pub fn preferred<'a>(&self, supported: &[&'a str]) -> Option<&'a str> {
+        let mut best: Option<(&'a str, f32)> = None;
+        for media_type in supported {
+            let quality = self.quality(media_type);
+            if quality > 0.0 && best.is_none_or(|(_, q)| quality > q) {
+                best = Some((media_type, quality));
+            }
+        }
+        best.map(|(media_type, _)| media_type)
+    }
+}
+
+impl<S> FromRequestParts<S> for Accept
+where
+    S: Send + Sync,
+{
+    type Rejection = Infallible;
+
+    async fn from_request_parts(parts: &mut Parts, _: &S) -> Result<Self, Self::Rejection> {
+        Ok(Self::from_headers(&parts.headers))
+    }
+}
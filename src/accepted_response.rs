@@ -0,0 +1,109 @@
+use axum::Json;
+use axum::http::StatusCode;
+use axum::http::header::HeaderValue;
+use axum::http::header::LOCATION;
+use axum::http::header::RETRY_AFTER;
+use axum::response::IntoResponse;
+use serde::Serialize;
+use std::{collections::BTreeMap, convert::TryFrom, marker::PhantomData, time::Duration};
+use utoipa::openapi::content::ContentBuilder;
+use utoipa::openapi::header::HeaderBuilder;
+use utoipa::openapi::{RefOr, Response, ResponseBuilder, ResponsesBuilder};
+use utoipa::{IntoResponses, PartialSchema, ToSchema};
+
+use crate::content_type_trait::{ContentTypeJson, ContentTypeTrait};
+use crate::cookies::{Cookies, impl_with_cookie};
+use crate::response_description_trait::{DefaultDescription, ResponseDescriptionTrait};
+
+pub struct AcceptedResponse<T, D = DefaultDescription>
+where
+    T: ToSchema + Serialize + Send,
+    D: ResponseDescriptionTrait,
+{
+    data: T,
+    location: String,
+    retry_after: Option<Duration>,
+    cookies: Cookies,
+    phantom: PhantomData<D>,
+}
+
+impl<T, D> AcceptedResponse<T, D>
+where
+    T: ToSchema + Serialize + Send,
+    D: ResponseDescriptionTrait,
+{
+    pub fn new(data: T, location: impl Into<String>) -> Self {
+        Self {
+            data,
+            location: location.into(),
+            retry_after: None,
+            cookies: Cookies::default(),
+            phantom: PhantomData,
+        }
+    }
+
+    pub fn location(&self) -> &str {
+        &self.location
+    }
+
+    #[must_use]
+    pub fn with_retry_after(mut self, retry_after: Duration) -> Self {
+        self.retry_after = Some(retry_after);
+        self
+    }
+
+    impl_with_cookie!();
+}
+
+impl<T, D> IntoResponse for AcceptedResponse<T, D>
+where
+    T: ToSchema + Serialize + Send,
+    D: ResponseDescriptionTrait,
+{
+    fn into_response(self) -> axum::response::Response {
+        let Ok(location) = <HeaderValue as TryFrom<String>>::try_from(self.location) else {
+            return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+        };
+        let mut res = (StatusCode::ACCEPTED, Json(self.data)).into_response();
+        res.headers_mut().insert(LOCATION, location);
+        if let Some(retry_after) = self.retry_after {
+            let mut seconds = retry_after.as_secs();
+            if retry_after.subsec_nanos() > 0 {
+                seconds += 1;
+            }
+            res.headers_mut()
+                .insert(RETRY_AFTER, HeaderValue::from(seconds));
+        }
+        self.cookies.apply(res.headers_mut());
+        res
+    }
+}
+
+impl<T, D> IntoResponses for AcceptedResponse<T, D>
+where
+    T: ToSchema + Serialize + Send,
+    D: ResponseDescriptionTrait,
+{
+    fn responses() -> BTreeMap<String, RefOr<Response>> {
+        let location = HeaderBuilder::new()
+            .schema(String::schema())
+            .description(Some("URL of the operation status resource"))
+            .build();
+        let retry_after = HeaderBuilder::new()
+            .schema(u64::schema())
+            .description(Some("Seconds to wait before polling the status"))
+            .build();
+        let content = ContentBuilder::new().schema(Some(T::schema())).build();
+        ResponsesBuilder::new()
+            .response(
+                StatusCode::ACCEPTED.as_str(),
+                ResponseBuilder::new()
+                    .description(D::description())
+                    .header("Location", location)
+                    .header("Retry-After", retry_after)
+                    .content(ContentTypeJson::content_type(), content),
+            )
+            .build()
+            .into()
+    }
+}
@@ -0,0 +1,213 @@
+use axum::http::HeaderMap;
+use axum::http::header::CACHE_CONTROL;
+use axum::http::header::EXPIRES;
+use axum::http::header::HeaderName;
+use axum::http::header::HeaderValue;
+use axum::http::header::VARY;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use utoipa::PartialSchema;
+use utoipa::openapi::ResponseBuilder;
+use utoipa::openapi::header::HeaderBuilder;
+
+pub const SURROGATE_CONTROL: &str = "surrogate-control";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CacheVisibility {
+    Public,
+    Private,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct CachePolicy {
+    visibility: Option<CacheVisibility>,
+    no_store: bool,
+    max_age: Option<Duration>,
+    s_maxage: Option<Duration>,
+    stale_while_revalidate: Option<Duration>,
+    immutable: bool,
+    vary: Vec<String>,
+    expires: Option<SystemTime>,
+    surrogate_max_age: Option<Duration>,
+}
+
+impl CachePolicy {
+    pub fn public() -> Self {
+        Self {
+            visibility: Some(CacheVisibility::Public),
+            ..Self::default()
+        }
+    }
+
+    pub fn private() -> Self {
+        Self {
+            visibility: Some(CacheVisibility::Private),
+            ..Self::default()
+        }
+    }
+
+    pub fn no_store() -> Self {
+        Self {
+            no_store: true,
+            ..Self::default()
+        }
+    }
+
+    #[must_use]
+    pub fn with_max_age(mut self, max_age: Duration) -> Self {
+        self.max_age = Some(max_age);
+        self
+    }
+
+    #[must_use]
+    pub fn with_s_maxage(mut self, s_maxage: Duration) -> Self {
+        self.s_maxage = Some(s_maxage);
+        self
+    }
+
+    #[must_use]
+    pub fn with_stale_while_revalidate(mut self, stale: Duration) -> Self {
+        self.stale_while_revalidate = Some(stale);
+        self
+    }
+
+    #[must_use]
+    pub fn immutable(mut self) -> Self {
+        self.immutable = true;
+        self
+    }
+
+    #[must_use]
+    pub fn with_vary(mut self, header: impl Into<String>) -> Self {
+        self.vary.push(header.into());
+        self
+    }
+
+    #[must_use]
+    pub fn with_expires(mut self, expires: SystemTime) -> Self {
+        self.expires = Some(expires);
+        self
+    }
+
+    #[must_use]
+    pub fn with_surrogate_max_age(mut self, max_age: Duration) -> Self {
+        self.surrogate_max_age = Some(max_age);
+        self
+    }
+
+    pub fn cache_control(&self) -> String {
+        let mut directives = Vec::new();
+        match self.visibility {
+            Some(CacheVisibility::Public) => directives.push("public".to_string()),
+            Some(CacheVisibility::Private) => directives.push("private".to_string()),
+            None => {}
+        }
+        if self.no_store {
+            directives.push("no-store".into());
+        }
+        if let Some(max_age) = self.max_age {
+            directives.push(format!("max-age={}", max_age.as_secs()));
+        }
+        if let Some(s_maxage) = self.s_maxage {
+            directives.push(format!("s-maxage={}", s_maxage.as_secs()));
+        }
+        if let Some(stale) = self.stale_while_revalidate {
+            directives.push(format!("stale-while-revalidate={}", stale.as_secs()));
+        }
+        if self.immutable {
+            directives.push("immutable".into());
+        }
+        directives.join(", ")
+    }
+
+    pub fn apply(&self, headers: &mut HeaderMap) {
+        let cache_control = self.cache_control();
+        if !cache_control.is_empty()
+            && let Ok(value) = HeaderValue::try_from(cache_control)
+        {
+            headers.insert(CACHE_CONTROL, value);
+        }
+        let listed: Vec<String> = headers
+            .get_all(VARY)
+            .iter()
+            .filter_map(|value| value.to_str().ok())
+            .flat_map(|value| value.split(','))
+            .map(|name| name.trim().to_string())
+            .collect();
+        let missing: Vec<_> = self
+            .vary
+            .iter()
+            .filter(|name| {
+                !listed
+                    .iter()
+                    .any(|l| l == "*" || l.eq_ignore_ascii_case(name))
+            })
+            .map(String::as_str)
+            .collect();
+        if !missing.is_empty()
+            && let Ok(value) = HeaderValue::try_from(missing.join(", "))
+        {
+            headers.append(VARY, value);
+        }
+        if let Some(expires) = self.expires
+            && let Ok(value) = HeaderValue::try_from(http_date(expires))
+        {
+            headers.insert(EXPIRES, value);
+        }
+        if let Some(max_age) = self.surrogate_max_age
+            && let Ok(value) = HeaderValue::try_from(format!("max-age={}", max_age.as_secs()))
+        {
+            headers.insert(HeaderName::from_static(SURROGATE_CONTROL), value);
+        }
+    }
+
+    pub fn document(&self, mut response: ResponseBuilder) -> ResponseBuilder {
+        let header = |description: String| {
+            HeaderBuilder::new()
+                .schema(String::schema())
+                .description(Some(description))
+                .build()
+        };
+        let cache_control = self.cache_control();
+        if !cache_control.is_empty() {
+            response = response.header("Cache-Control", header(cache_control));
+        }
+        if !self.vary.is_empty() {
+            response = response.header("Vary", header(self.vary.join(", ")));
+        }
+        if self.expires.is_some() {
+            response = response.header("Expires", header("Expiry date of the response".into()));
+        }
+        if let Some(max_age) = self.surrogate_max_age {
+            response = response.header(
+                "Surrogate-Control",
+                header(format!("max-age={}", max_age.as_secs())),
+            );
+        }
+        response
+    }
+}
+
+pub fn http_date(time: SystemTime) -> String {
+    const WEEKDAYS: [&str; 7] = ["Sun", "Mon", "Tue", "Wed", "Thu", "Fri", "Sat"];
+    const MONTHS: [&str; 12] = [
+        "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+    ];
+    let secs = time.duration_since(UNIX_EPOCH).map_or(0, |d| d.as_secs());
+    let days = secs / 86_400;
+    let (hour, minute, second) = (secs % 86_400 / 3600, secs % 3600 / 60, secs % 60);
+    let weekday = WEEKDAYS[((days + 4) % 7) as usize];
+
+    let z = days + 719_468;
+    let era = z / 146_097;
+    let doe = z - era * 146_097;
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + u64::from(month <= 2);
+    format!(
+        "{weekday}, {day:02} {} {year} {hour:02}:{minute:02}:{second:02} GMT",
+        MONTHS[(month - 1) as usize]
+    )
+}
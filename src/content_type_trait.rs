@@ -7,6 +7,14 @@ pub struct ContentTypeCss {}
 pub struct ContentTypeJs {}
 
 pub struct ContentTypeJson {}
+pub struct ContentTypeProblemJson {}
+pub struct ContentTypeNdjson {}
+pub struct ContentTypeEventStream {}
+pub struct ContentTypeYaml {}
+pub struct ContentTypeXml {}
+pub struct ContentTypeMsgpack {}
+pub struct ContentTypeHal {}
+pub struct ContentTypeJsonApi {}
 
 #[macro_export]
 macro_rules! derive_content_type {
@@ -20,9 +28,31 @@ macro_rules! derive_content_type {
             }
         }
     };
+    ($T:ty, $content_type:expr, without_charset) => {
+        impl $crate::content_type_trait::ContentTypeTrait for $T {
+            fn content_type() -> &'static str {
+                $content_type
+            }
+            fn content_type_header() -> &'static str {
+                $content_type
+            }
+        }
+    };
 }
 
 derive_content_type!(ContentTypeHtml, "text/html");
 derive_content_type!(ContentTypeCss, "text/css");
 derive_content_type!(ContentTypeJs, "text/javascript");
 derive_content_type!(ContentTypeJson, "application/json");
+derive_content_type!(ContentTypeProblemJson, "application/problem+json");
+derive_content_type!(ContentTypeNdjson, "application/x-ndjson");
+derive_content_type!(ContentTypeEventStream, "text/event-stream");
+derive_content_type!(ContentTypeYaml, "application/yaml");
+derive_content_type!(ContentTypeXml, "application/xml");
+derive_content_type!(ContentTypeMsgpack, "application/msgpack");
+derive_content_type!(ContentTypeHal, "application/hal+json");
+derive_content_type!(
+    ContentTypeJsonApi,
+    "application/vnd.api+json",
+    without_charset
+);
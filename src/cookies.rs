@@ -0,0 +1,32 @@
+use axum::http::HeaderMap;
+use axum::http::header::HeaderValue;
+use axum::http::header::SET_COOKIE;
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Cookies(Vec<String>);
+
+impl Cookies {
+    pub fn push(&mut self, cookie: impl Into<String>) {
+        self.0.push(cookie.into());
+    }
+
+    pub fn apply(self, headers: &mut HeaderMap) {
+        for cookie in self.0 {
+            if let Ok(value) = HeaderValue::try_from(cookie) {
+                headers.append(SET_COOKIE, value);
+            }
+        }
+    }
+}
+
+macro_rules! impl_with_cookie {
+    () => {
+        #[must_use]
+        pub fn with_cookie(mut self, cookie: impl Into<String>) -> Self {
+            self.cookies.push(cookie);
+            self
+        }
+    };
+}
+
+pub(crate) use impl_with_cookie;
@@ -0,0 +1,182 @@
+use axum::Json;
+use axum::http::StatusCode;
+use axum::http::header::HeaderValue;
+use axum::http::header::LOCATION;
+use axum::response::IntoResponse;
+use serde::Serialize;
+use serde_json::Value;
+use std::{collections::BTreeMap, convert::TryFrom, fmt::Write, marker::PhantomData};
+use utoipa::openapi::content::ContentBuilder;
+use utoipa::openapi::header::HeaderBuilder;
+use utoipa::openapi::{RefOr, Response, ResponseBuilder, ResponsesBuilder};
+use utoipa::{IntoResponses, PartialSchema, ToSchema};
+
+use crate::content_type_trait::{ContentTypeJson, ContentTypeTrait};
+use crate::cookies::{Cookies, impl_with_cookie};
+use crate::problem_details::{
+    ABOUT_BLANK, IntoProblemDetails, ProblemDetails, ProblemVariant, problem_responses,
+};
+use crate::response_description_trait::{DefaultDescription, ResponseDescriptionTrait};
+
+pub fn location_from_template<P: Serialize>(
+    template: &str,
+    params: &P,
+) -> Result<String, LocationError> {
+    let Value::Object(params) =
+        serde_json::to_value(params).map_err(|e| LocationError::Params(e.to_string()))?
+    else {
+        return Err(LocationError::Params(
+            "path parameters must serialize to a struct or map".into(),
+        ));
+    };
+    let mut location = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some((prefix, tail)) = rest.split_once('{') {
+        let (name, tail) = tail
+            .split_once('}')
+            .ok_or_else(|| LocationError::Template(template.into()))?;
+        let value = match params.get(name) {
+            Some(Value::String(value)) => value.clone(),
+            Some(value @ (Value::Number(_) | Value::Bool(_))) => value.to_string(),
+            _ => return Err(LocationError::MissingParam(name.into())),
+        };
+        location.push_str(prefix);
+        percent_encode(&value, &mut location);
+        rest = tail;
+    }
+    location.push_str(rest);
+    Ok(location)
+}
+
+pub(crate) fn percent_encode(value: &str, output: &mut String) {
+    for byte in value.bytes() {
+        if byte.is_ascii_alphanumeric() || b"-._~".contains(&byte) {
+            output.push(byte as char);
+        } else {
+            let _ = write!(output, "%{byte:02X}");
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum LocationError {
+    Template(String),
+    MissingParam(String),
+    Params(String),
+}
+
+impl std::fmt::Display for LocationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Template(template) => write!(f, "unterminated parameter in {template}"),
+            Self::MissingParam(name) => write!(f, "missing path parameter {name}"),
+            Self::Params(e) => write!(f, "invalid path parameters: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for LocationError {}
+
+impl IntoProblemDetails for LocationError {
+    fn problem_details(&self) -> ProblemDetails {
+        ProblemDetails::new(StatusCode::INTERNAL_SERVER_ERROR)
+    }
+}
+
+impl IntoResponse for LocationError {
+    fn into_response(self) -> axum::response::Response {
+        tracing::error!(error = %self, "building the Location header failed");
+        self.problem_details().into_response()
+    }
+}
+
+impl IntoResponses for LocationError {
+    fn responses() -> BTreeMap<String, RefOr<Response>> {
+        problem_responses(&[ProblemVariant {
+            name: "LocationError",
+            status: StatusCode::INTERNAL_SERVER_ERROR,
+            problem_type: ABOUT_BLANK,
+            title: None,
+        }])
+    }
+}
+
+pub struct CreatedResponse<T, D = DefaultDescription>
+where
+    T: ToSchema + Serialize + Send,
+    D: ResponseDescriptionTrait,
+{
+    data: T,
+    location: String,
+    cookies: Cookies,
+    phantom: PhantomData<D>,
+}
+
+impl<T, D> CreatedResponse<T, D>
+where
+    T: ToSchema + Serialize + Send,
+    D: ResponseDescriptionTrait,
+{
+    pub fn new(data: T, location: impl Into<String>) -> Self {
+        Self {
+            data,
+            location: location.into(),
+            cookies: Cookies::default(),
+            phantom: PhantomData,
+        }
+    }
+
+    pub fn from_template<P: Serialize>(
+        data: T,
+        template: &str,
+        params: &P,
+    ) -> Result<Self, LocationError> {
+        location_from_template(template, params).map(|location| Self::new(data, location))
+    }
+
+    pub fn location(&self) -> &str {
+        &self.location
+    }
+
+    impl_with_cookie!();
+}
+
+impl<T, D> IntoResponse for CreatedResponse<T, D>
+where
+    T: ToSchema + Serialize + Send,
+    D: ResponseDescriptionTrait,
+{
+    fn into_response(self) -> axum::response::Response {
+        let Ok(location) = <HeaderValue as TryFrom<String>>::try_from(self.location) else {
+            return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+        };
+        let mut res = (StatusCode::CREATED, Json(self.data)).into_response();
+        res.headers_mut().insert(LOCATION, location);
+        self.cookies.apply(res.headers_mut());
+        res
+    }
+}
+
+impl<T, D> IntoResponses for CreatedResponse<T, D>
+where
+    T: ToSchema + Serialize + Send,
+    D: ResponseDescriptionTrait,
+{
+    fn responses() -> BTreeMap<String, RefOr<Response>> {
+        let location = HeaderBuilder::new()
+            .schema(String::schema())
+            .description(Some("URL of the created resource"))
+            .build();
+        let content = ContentBuilder::new().schema(Some(T::schema())).build();
+        ResponsesBuilder::new()
+            .response(
+                StatusCode::CREATED.as_str(),
+                ResponseBuilder::new()
+                    .description(D::description())
+                    .header("Location", location)
+                    .content(ContentTypeJson::content_type(), content),
+            )
+            .build()
+            .into()
+    }
+}
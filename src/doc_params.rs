@@ -0,0 +1,308 @@
+use axum::extract::path::ErrorKind;
+use axum::extract::rejection::PathRejection;
+use axum::extract::{FromRequestParts, Path};
+use axum::http::StatusCode;
+use axum::http::header::COOKIE;
+use axum::http::request::Parts;
+use axum::response::IntoResponse;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::{collections::BTreeMap, fmt};
+use utoipa::openapi::path::{Parameter, ParameterIn};
+use utoipa::openapi::{RefOr, Required, Response};
+use utoipa::{IntoParams, IntoResponses, ToSchema};
+
+use crate::created_response::percent_encode;
+use crate::problem_details::{
+    ABOUT_BLANK, IntoProblemDetails, ProblemDetails, ProblemVariant, problem_responses,
+};
+
+/// Where a rejected parameter was read from.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum ParamLocation {
+    Path,
+    Query,
+    Header,
+    Cookie,
+}
+
+impl ParamLocation {
+    pub fn parameter_in(self) -> ParameterIn {
+        match self {
+            Self::Path => ParameterIn::Path,
+            Self::Query => ParameterIn::Query,
+            Self::Header => ParameterIn::Header,
+            Self::Cookie => ParameterIn::Cookie,
+        }
+    }
+}
+
+impl fmt::Display for ParamLocation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Path => "path",
+            Self::Query => "query",
+            Self::Header => "header",
+            Self::Cookie => "cookie",
+        })
+    }
+}
+
+fn params_in<T: IntoParams>(location: ParamLocation) -> Vec<Parameter> {
+    T::into_params(|| Some(location.parameter_in()))
+        .into_iter()
+        .map(|mut param| {
+            param.parameter_in = location.parameter_in();
+            if location == ParamLocation::Path {
+                param.required = Required::True;
+            }
+            param
+        })
+        .collect()
+}
+
+fn from_query<T: DeserializeOwned>(
+    query: &str,
+    location: ParamLocation,
+) -> Result<T, ParamsRejection> {
+    let deserializer =
+        serde_urlencoded::Deserializer::new(form_urlencoded::parse(query.as_bytes()));
+    serde_path_to_error::deserialize(deserializer).map_err(|e| {
+        let message = e.inner().to_string();
+        let name = match e.path().to_string() {
+            path if path != "." => Some(path),
+            _ => message
+                .strip_prefix("missing field `")
+                .and_then(|rest| rest.split('`').next())
+                .map(str::to_string),
+        };
+        ParamsRejection {
+            name,
+            ..ParamsRejection::new(location, message)
+        }
+    })
+}
+
+fn from_pairs<'a, T: DeserializeOwned>(
+    pairs: impl IntoIterator<Item = (&'a str, &'a str)>,
+    location: ParamLocation,
+) -> Result<T, ParamsRejection> {
+    let mut query = String::new();
+    for (i, (name, value)) in pairs.into_iter().enumerate() {
+        if i > 0 {
+            query.push('&');
+        }
+        percent_encode(name, &mut query);
+        query.push('=');
+        percent_encode(value, &mut query);
+    }
+    from_query(&query, location)
+}
+
+fn path_rejection(rejection: &PathRejection) -> ParamsRejection {
+    let name = match rejection {
+        PathRejection::FailedToDeserializePathParams(e) => match e.kind() {
+            ErrorKind::ParseErrorAtKey { key, .. }
+            | ErrorKind::InvalidUtf8InPathParam { key }
+            | ErrorKind::DeserializeError { key, .. } => Some(key.clone()),
+            _ => None,
+        },
+        _ => None,
+    };
+    ParamsRejection {
+        name,
+        ..ParamsRejection::new(ParamLocation::Path, rejection.body_text())
+            .with_status(rejection.status())
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct DocPath<T>(pub T);
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct DocQuery<T>(pub T);
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct DocHeaders<T>(pub T);
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct DocCookies<T>(pub T);
+
+impl<T, S> FromRequestParts<S> for DocPath<T>
+where
+    T: DeserializeOwned + Send,
+    S: Send + Sync,
+{
+    type Rejection = ParamsRejection;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        Path::<T>::from_request_parts(parts, state)
+            .await
+            .map(|Path(value)| Self(value))
+            .map_err(|e| path_rejection(&e))
+    }
+}
+
+impl<T, S> FromRequestParts<S> for DocQuery<T>
+where
+    T: DeserializeOwned,
+    S: Send + Sync,
+{
+    type Rejection = ParamsRejection;
+
+    async fn from_request_parts(parts: &mut Parts, _: &S) -> Result<Self, Self::Rejection> {
+        let query = parts.uri.query().unwrap_or_default();
+        from_query(query, ParamLocation::Query).map(Self)
+    }
+}
+
+impl<T, S> FromRequestParts<S> for DocHeaders<T>
+where
+    T: DeserializeOwned,
+    S: Send + Sync,
+{
+    type Rejection = ParamsRejection;
+
+    async fn from_request_parts(parts: &mut Parts, _: &S) -> Result<Self, Self::Rejection> {
+        let pairs = parts
+            .headers
+            .iter()
+            .filter_map(|(name, value)| Some((name.as_str(), value.to_str().ok()?)));
+        from_pairs(pairs, ParamLocation::Header).map(Self)
+    }
+}
+
+impl<T, S> FromRequestParts<S> for DocCookies<T>
+where
+    T: DeserializeOwned,
+    S: Send + Sync,
+{
+    type Rejection = ParamsRejection;
+
+    async fn from_request_parts(parts: &mut Parts, _: &S) -> Result<Self, Self::Rejection> {
+        let pairs = parts
+            .headers
+            .get_all(COOKIE)
+            .iter()
+            .filter_map(|value| value.to_str().ok())
+            .flat_map(|value| value.split(';'))
+            .filter_map(|cookie| cookie.trim().split_once('='));
+        from_pairs(pairs, ParamLocation::Cookie).map(Self)
+    }
+}
+
+impl<T: IntoParams> IntoParams for DocPath<T> {
+    fn into_params(_: impl Fn() -> Option<ParameterIn>) -> Vec<Parameter> {
+        params_in::<T>(ParamLocation::Path)
+    }
+}
+
+impl<T: IntoParams> IntoParams for DocQuery<T> {
+    fn into_params(_: impl Fn() -> Option<ParameterIn>) -> Vec<Parameter> {
+        params_in::<T>(ParamLocation::Query)
+    }
+}
+
+impl<T: IntoParams> IntoParams for DocHeaders<T> {
+    fn into_params(_: impl Fn() -> Option<ParameterIn>) -> Vec<Parameter> {
+        params_in::<T>(ParamLocation::Header)
+    }
+}
+
+impl<T: IntoParams> IntoParams for DocCookies<T> {
+    fn into_params(_: impl Fn() -> Option<ParameterIn>) -> Vec<Parameter> {
+        params_in::<T>(ParamLocation::Cookie)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ParamsRejection {
+    pub location: ParamLocation,
+    pub name: Option<String>,
+    pub message: String,
+    pub status: StatusCode,
+}
+
+impl ParamsRejection {
+    pub fn new(location: ParamLocation, message: impl Into<String>) -> Self {
+        Self {
+            location,
+            name: None,
+            message: message.into(),
+            status: StatusCode::BAD_REQUEST,
+        }
+    }
+
+    #[must_use]
+    pub fn with_name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    #[must_use]
+    pub fn with_status(mut self, status: StatusCode) -> Self {
+        self.status = status;
+        self
+    }
+}
+
+impl fmt::Display for ParamsRejection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.name {
+            Some(name) => write!(
+                f,
+                "invalid {} parameter `{name}`: {}",
+                self.location, self.message
+            ),
+            None => write!(f, "invalid {} parameters: {}", self.location, self.message),
+        }
+    }
+}
+
+impl std::error::Error for ParamsRejection {}
+
+impl IntoProblemDetails for ParamsRejection {
+    fn problem_details(&self) -> ProblemDetails {
+        if self.status.is_server_error() {
+            return ProblemDetails::new(self.status);
+        }
+        let problem = ProblemDetails::new(self.status)
+            .with_title("Invalid parameters")
+            .with_detail(self.to_string())
+            .with_extension("in", self.location.to_string());
+        match &self.name {
+            Some(name) => problem.with_extension("name", name.clone()),
+            None => problem,
+        }
+    }
+}
+
+impl IntoResponse for ParamsRejection {
+    fn into_response(self) -> axum::response::Response {
+        self.problem_details().into_response()
+    }
+}
+
+impl IntoResponses for ParamsRejection {
+    fn responses() -> BTreeMap<String, RefOr<Response>> {
+        let invalid = |name| ProblemVariant {
+            name,
+            status: StatusCode::BAD_REQUEST,
+            problem_type: ABOUT_BLANK,
+            title: Some("Invalid parameters"),
+        };
+        problem_responses(&[
+            invalid("path"),
+            invalid("query"),
+            invalid("header"),
+            invalid("cookie"),
+            ProblemVariant {
+                name: "MissingPathParams",
+                status: StatusCode::INTERNAL_SERVER_ERROR,
+                problem_type: ABOUT_BLANK,
+                title: None,
+            },
+        ])
+    }
+}
@@ -0,0 +1,101 @@
+use axum::body::Body;
+use axum::http::header::CONTENT_LENGTH;
+use axum::http::header::CONTENT_TYPE;
+use axum::response::{IntoResponse, Response};
+use std::{borrow::Cow, collections::BTreeMap, marker::PhantomData};
+use utoipa::openapi::{RefOr, ResponseBuilder, ResponsesBuilder};
+use utoipa::{IntoResponses, PartialSchema, ToSchema};
+
+use crate::cookies::{Cookies, impl_with_cookie};
+use crate::response_description_trait::{DefaultDescription, ResponseDescriptionTrait};
+use crate::status_code_trait::{StatusCodeNoContent, StatusCodeTrait, status_allows_body};
+
+pub fn strip_forbidden_body(res: &mut Response) {
+    if !status_allows_body(res.status()) {
+        *res.body_mut() = Body::empty();
+        res.headers_mut().remove(CONTENT_TYPE);
+        res.headers_mut().remove(CONTENT_LENGTH);
+    }
+}
+
+pub struct EmptyResponse<S = StatusCodeNoContent, D = DefaultDescription>
+where
+    S: StatusCodeTrait,
+    D: ResponseDescriptionTrait,
+{
+    cookies: Cookies,
+    phantom: PhantomData<(S, D)>,
+}
+
+impl<S, D> Default for EmptyResponse<S, D>
+where
+    S: StatusCodeTrait,
+    D: ResponseDescriptionTrait,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<S, D> EmptyResponse<S, D>
+where
+    S: StatusCodeTrait,
+    D: ResponseDescriptionTrait,
+{
+    pub fn new() -> Self {
+        Self {
+            cookies: Cookies::default(),
+            phantom: PhantomData,
+        }
+    }
+
+    impl_with_cookie!();
+}
+
+impl<S, D> IntoResponse for EmptyResponse<S, D>
+where
+    S: StatusCodeTrait,
+    D: ResponseDescriptionTrait,
+{
+    fn into_response(self) -> Response {
+        let mut res = S::status_code().into_response();
+        self.cookies.apply(res.headers_mut());
+        res
+    }
+}
+
+impl<S, D> IntoResponses for EmptyResponse<S, D>
+where
+    S: StatusCodeTrait,
+    D: ResponseDescriptionTrait,
+{
+    fn responses() -> BTreeMap<String, RefOr<utoipa::openapi::Response>> {
+        ResponsesBuilder::new()
+            .response(
+                S::response_key(),
+                ResponseBuilder::new().description(D::description()),
+            )
+            .build()
+            .into()
+    }
+}
+
+impl<S, D> PartialSchema for EmptyResponse<S, D>
+where
+    S: StatusCodeTrait,
+    D: ResponseDescriptionTrait,
+{
+    fn schema() -> RefOr<utoipa::openapi::schema::Schema> {
+        <()>::schema()
+    }
+}
+
+impl<S, D> ToSchema for EmptyResponse<S, D>
+where
+    S: StatusCodeTrait,
+    D: ResponseDescriptionTrait,
+{
+    fn name() -> Cow<'static, str> {
+        <()>::name()
+    }
+}
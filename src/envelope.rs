@@ -0,0 +1,126 @@
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+use utoipa::openapi::RefOr;
+use utoipa::openapi::schema::{ArrayBuilder, ObjectBuilder, Schema};
+use utoipa::{PartialSchema, ToSchema};
+
+use crate::problem_details::ProblemDetails;
+use crate::schema_name::{schema_name, wrapper_name};
+
+pub trait EnvelopeMeta {
+    fn meta_name() -> Option<Cow<'static, str>>;
+
+    fn meta_schema() -> Option<RefOr<Schema>>;
+
+    fn meta_schemas(_schemas: &mut Vec<(String, RefOr<Schema>)>) {}
+}
+
+impl EnvelopeMeta for () {
+    fn meta_name() -> Option<Cow<'static, str>> {
+        None
+    }
+
+    fn meta_schema() -> Option<RefOr<Schema>> {
+        None
+    }
+}
+
+#[macro_export]
+macro_rules! derive_envelope_meta {
+    ($T:ty) => {
+        impl $crate::envelope::EnvelopeMeta for $T {
+            fn meta_name() -> Option<std::borrow::Cow<'static, str>> {
+                Some(<$T as utoipa::ToSchema>::name())
+            }
+
+            fn meta_schema() -> Option<utoipa::openapi::RefOr<utoipa::openapi::schema::Schema>> {
+                Some(<$T as utoipa::PartialSchema>::schema())
+            }
+
+            fn meta_schemas(
+                schemas: &mut Vec<(
+                    String,
+                    utoipa::openapi::RefOr<utoipa::openapi::schema::Schema>,
+                )>,
+            ) {
+                <$T as utoipa::ToSchema>::schemas(schemas)
+            }
+        }
+    };
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Envelope<T, M = ()> {
+    pub data: T,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub meta: Option<M>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub errors: Vec<ProblemDetails>,
+}
+
+impl<T, M> Envelope<T, M> {
+    pub fn new(data: T) -> Self {
+        Self {
+            data,
+            meta: None,
+            errors: Vec::new(),
+        }
+    }
+
+    #[must_use]
+    pub fn with_meta(mut self, meta: M) -> Self {
+        self.meta = Some(meta);
+        self
+    }
+
+    #[must_use]
+    pub fn with_error(mut self, error: ProblemDetails) -> Self {
+        self.errors.push(error);
+        self
+    }
+}
+
+impl<T, M> From<T> for Envelope<T, M> {
+    fn from(data: T) -> Self {
+        Self::new(data)
+    }
+}
+
+impl<T, M> PartialSchema for Envelope<T, M>
+where
+    T: ToSchema,
+    M: EnvelopeMeta,
+{
+    fn schema() -> RefOr<Schema> {
+        let mut object = ObjectBuilder::new()
+            .property("data", T::schema())
+            .required("data");
+        if let Some(meta) = M::meta_schema() {
+            object = object.property("meta", meta);
+        }
+        object
+            .property(
+                "errors",
+                ArrayBuilder::new().items(ProblemDetails::schema()),
+            )
+            .into()
+    }
+}
+
+impl<T, M> ToSchema for Envelope<T, M>
+where
+    T: ToSchema,
+    M: EnvelopeMeta,
+{
+    fn name() -> Cow<'static, str> {
+        match M::meta_name() {
+            Some(meta) => schema_name("Envelope", &[T::name(), meta]).into(),
+            None => wrapper_name::<T>("Envelope"),
+        }
+    }
+
+    fn schemas(schemas: &mut Vec<(String, RefOr<Schema>)>) {
+        T::schemas(schemas);
+        M::meta_schemas(schemas);
+    }
+}
@@ -0,0 +1,320 @@
+use axum::extract::FromRequestParts;
+use axum::http::HeaderMap;
+use axum::http::Method;
+use axum::http::StatusCode;
+use axum::http::header::HeaderValue;
+use axum::http::header::IF_MATCH;
+use axum::http::header::IF_NONE_MATCH;
+use axum::http::request::Parts;
+use axum::response::IntoResponse;
+use std::{collections::BTreeMap, convert::Infallible, fmt};
+use utoipa::openapi::header::{Header, HeaderBuilder};
+use utoipa::openapi::path::{Parameter, ParameterBuilder, ParameterIn};
+use utoipa::openapi::{RefOr, Required, Response};
+use utoipa::{IntoParams, IntoResponses, PartialSchema};
+
+use crate::problem_details::{
+    ABOUT_BLANK, IntoProblemDetails, ProblemDetails, ProblemVariant, problem_responses,
+};
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ETag {
+    Strong(String),
+    Weak(String),
+}
+
+impl ETag {
+    pub fn strong_from_bytes(bytes: impl AsRef<[u8]>) -> Self {
+        Self::Strong(fnv1a(bytes.as_ref()))
+    }
+
+    pub fn weak_from_bytes(bytes: impl AsRef<[u8]>) -> Self {
+        Self::Weak(fnv1a(bytes.as_ref()))
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        let value = value.trim();
+        let (weak, value) = match value.strip_prefix("W/") {
+            Some(value) => (true, value),
+            None => (false, value),
+        };
+        let tag = value.strip_prefix('"')?.strip_suffix('"')?;
+        if tag.contains('"') {
+            return None;
+        }
+        Some(if weak {
+            Self::Weak(tag.into())
+        } else {
+            Self::Strong(tag.into())
+        })
+    }
+
+    pub fn tag(&self) -> &str {
+        match self {
+            Self::Strong(tag) | Self::Weak(tag) => tag,
+        }
+    }
+
+    pub fn is_weak(&self) -> bool {
+        matches!(self, Self::Weak(_))
+    }
+
+    pub fn weak_eq(&self, other: &Self) -> bool {
+        self.tag() == other.tag()
+    }
+
+    pub fn strong_eq(&self, other: &Self) -> bool {
+        !self.is_weak() && !other.is_weak() && self.tag() == other.tag()
+    }
+
+    pub fn header_value(&self) -> Option<HeaderValue> {
+        HeaderValue::try_from(self.to_string()).ok()
+    }
+}
+
+impl fmt::Display for ETag {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Strong(tag) => write!(f, "\"{tag}\""),
+            Self::Weak(tag) => write!(f, "W/\"{tag}\""),
+        }
+    }
+}
+
+fn fnv1a(bytes: &[u8]) -> String {
+    let hash = bytes.iter().fold(0xcbf2_9ce4_8422_2325_u64, |hash, byte| {
+        (hash ^ u64::from(*byte)).wrapping_mul(0x0100_0000_01b3)
+    });
+    format!("{hash:016x}")
+}
+
+fn parse_etags(value: &str) -> Vec<ETag> {
+    let mut tags = Vec::new();
+    let mut rest = value;
+    loop {
+        rest = rest.trim_start_matches(|c: char| c == ',' || c.is_whitespace());
+        if rest.is_empty() {
+            return tags;
+        }
+        let prefix = if rest.starts_with("W/") { 2 } else { 0 };
+        let end = rest[prefix..]
+            .strip_prefix('"')
+            .and_then(|quoted| quoted.find('"'))
+            .map(|close| prefix + close + 2);
+        match end {
+            Some(end) => {
+                tags.extend(ETag::parse(&rest[..end]));
+                rest = &rest[end..];
+            }
+            None => rest = rest.split_once(',').map_or("", |(_, rest)| rest),
+        }
+    }
+}
+
+fn parse_etag_list(headers: &HeaderMap, name: &str) -> Option<Option<Vec<ETag>>> {
+    let values: Vec<_> = headers
+        .get_all(name)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .collect();
+    if values.is_empty() {
+        return None;
+    }
+    if values.iter().any(|value| value.trim() == "*") {
+        return Some(None);
+    }
+    let tags = values.iter().flat_map(|value| parse_etags(value)).collect();
+    Some(Some(tags))
+}
+
+pub fn etag_header() -> Header {
+    HeaderBuilder::new()
+        .schema(String::schema())
+        .description(Some("Entity tag of the returned representation"))
+        .build()
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub enum EntityTags {
+    #[default]
+    Absent,
+    Any,
+    Tags(Vec<ETag>),
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct IfNoneMatch {
+    pub method: Method,
+    pub tags: EntityTags,
+}
+
+impl IfNoneMatch {
+    pub fn new(method: Method, tags: EntityTags) -> Self {
+        Self { method, tags }
+    }
+
+    pub fn from_request(method: &Method, headers: &HeaderMap) -> Self {
+        let tags = match parse_etag_list(headers, IF_NONE_MATCH.as_str()) {
+            None => EntityTags::Absent,
+            Some(None) => EntityTags::Any,
+            Some(Some(tags)) => EntityTags::Tags(tags),
+        };
+        Self::new(method.clone(), tags)
+    }
+
+    pub fn matches(&self, etag: &ETag) -> bool {
+        match &self.tags {
+            EntityTags::Absent => false,
+            EntityTags::Any => true,
+            EntityTags::Tags(tags) => tags.iter().any(|tag| tag.weak_eq(etag)),
+        }
+    }
+
+    pub fn precondition_response(
+        &self,
+        etag: &ETag,
+        status: StatusCode,
+    ) -> Option<axum::response::Response> {
+        if !status.is_success() || !self.matches(etag) {
+            return None;
+        }
+        if self.method == Method::GET || self.method == Method::HEAD {
+            Some(StatusCode::NOT_MODIFIED.into_response())
+        } else {
+            Some(PreconditionError::Failed.into_response())
+        }
+    }
+}
+
+impl<S> FromRequestParts<S> for IfNoneMatch
+where
+    S: Send + Sync,
+{
+    type Rejection = Infallible;
+
+    async fn from_request_parts(parts: &mut Parts, _: &S) -> Result<Self, Self::Rejection> {
+        Ok(Self::from_request(&parts.method, &parts.headers))
+    }
+}
+
+impl IntoParams for IfNoneMatch {
+    fn into_params(_: impl Fn() -> Option<ParameterIn>) -> Vec<Parameter> {
+        vec![
+            ParameterBuilder::new()
+                .name("If-None-Match")
+                .parameter_in(ParameterIn::Header)
+                .description(Some("Entity tags of cached representations"))
+                .schema(Some(String::schema()))
+                .build(),
+        ]
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub enum IfMatch {
+    #[default]
+    Absent,
+    Any,
+    Tags(Vec<ETag>),
+}
+
+impl IfMatch {
+    pub fn from_headers(headers: &HeaderMap) -> Self {
+        match parse_etag_list(headers, IF_MATCH.as_str()) {
+            None => Self::Absent,
+            Some(None) => Self::Any,
+            Some(Some(tags)) => Self::Tags(tags),
+        }
+    }
+
+    pub fn check(&self, current: &ETag) -> Result<(), PreconditionError> {
+        match self {
+            Self::Absent => Err(PreconditionError::Required),
+            Self::Any => Ok(()),
+            Self::Tags(tags) if tags.iter().any(|tag| tag.strong_eq(current)) => Ok(()),
+            Self::Tags(_) => Err(PreconditionError::Failed),
+        }
+    }
+}
+
+impl<S> FromRequestParts<S> for IfMatch
+where
+    S: Send + Sync,
+{
+    type Rejection = Infallible;
+
+    async fn from_request_parts(parts: &mut Parts, _: &S) -> Result<Self, Self::Rejection> {
+        Ok(Self::from_headers(&parts.headers))
+    }
+}
+
+impl IntoParams for IfMatch {
+    fn into_params(_: impl Fn() -> Option<ParameterIn>) -> Vec<Parameter> {
+        vec![
+            ParameterBuilder::new()
+                .name("If-Match")
+                .parameter_in(ParameterIn::Header)
+                .required(Required::True)
+                .description(Some("Entity tag of the version being updated"))
+                .schema(Some(String::schema()))
+                .build(),
+        ]
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PreconditionError {
+    Failed,
+    Required,
+}
+
+impl PreconditionError {
+    pub fn status_code(self) -> StatusCode {
+        match self {
+            Self::Failed => StatusCode::PRECONDITION_FAILED,
+            Self::Required => StatusCode::PRECONDITION_REQUIRED,
+        }
+    }
+}
+
+impl fmt::Display for PreconditionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Failed => f.write_str("the resource was modified since it was read"),
+            Self::Required => f.write_str("the request must be conditional on If-Match"),
+        }
+    }
+}
+
+impl std::error::Error for PreconditionError {}
+
+impl IntoProblemDetails for PreconditionError {
+    fn problem_details(&self) -> ProblemDetails {
+        ProblemDetails::new(self.status_code()).with_detail(self.to_string())
+    }
+}
+
+impl IntoResponse for PreconditionError {
+    fn into_response(self) -> axum::response::Response {
+        self.problem_details().into_response()
+    }
+}
+
+impl IntoResponses for PreconditionError {
+    fn responses() -> BTreeMap<String, RefOr<Response>> {
+        problem_responses(&[
+            ProblemVariant {
+                name: "Failed",
+                status: StatusCode::PRECONDITION_FAILED,
+                problem_type: ABOUT_BLANK,
+                title: Some("Precondition Failed"),
+            },
+            ProblemVariant {
+                name: "Required",
+                status: StatusCode::PRECONDITION_REQUIRED,
+                problem_type: ABOUT_BLANK,
+                title: Some("Precondition Required"),
+            },
+        ])
+    }
+}
@@ -1,18 +1,28 @@
 use axum::body::Body;
-use axum::http::header::HeaderValue;
-use axum::http::header::SET_COOKIE;
+use axum::http::StatusCode;
+use axum::http::header::ETAG;
 use axum::response::IntoResponse;
-use std::{borrow::Cow, convert::TryFrom};
+use std::borrow::Cow;
 use utoipa::PartialSchema;
 use utoipa::ToSchema;
 
+use crate::cache_policy::CachePolicy;
+use crate::cookies::{Cookies, impl_with_cookie};
+use crate::etag::{ETag, IfNoneMatch};
+use crate::schema_name::transparent_name;
+use crate::status_code_trait::ExplicitStatus;
+
 pub struct HtmlResponse<T>
 where
     T: ToSchema + Send,
     Body: From<T>,
 {
     data: T,
-    cookies: Option<Vec<String>>,
+    status: Option<StatusCode>,
+    etag: Option<ETag>,
+    if_none_match: IfNoneMatch,
+    cache_policy: Option<CachePolicy>,
+    cookies: Cookies,
 }
 
 impl<T> HtmlResponse<T>
@@ -23,17 +33,55 @@ where
     pub fn new(data: T) -> Self {
         Self {
             data,
-            cookies: None,
+            status: None,
+            etag: None,
+            if_none_match: IfNoneMatch::default(),
+            cache_policy: None,
+            cookies: Cookies::default(),
         }
     }
 
     #[must_use]
-    pub fn with_cookie(mut self, cookie: impl Into<String>) -> Self {
-        if let Some(cookies) = self.cookies.as_mut() {
-            cookies.push(cookie.into());
-        } else {
-            self.cookies = Some(vec![cookie.into()]);
-        }
+    pub fn with_status(mut self, status: StatusCode) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    #[must_use]
+    pub fn with_etag(mut self, etag: ETag) -> Self {
+        self.etag = Some(etag);
+        self
+    }
+
+    #[must_use]
+    pub fn with_if_none_match(mut self, if_none_match: IfNoneMatch) -> Self {
+        self.if_none_match = if_none_match;
+        self
+    }
+
+    #[must_use]
+    pub fn with_cache_policy(mut self, cache_policy: CachePolicy) -> Self {
+        self.cache_policy = Some(cache_policy);
+        self
+    }
+
+    impl_with_cookie!();
+}
+
+impl<T> HtmlResponse<T>
+where
+    T: ToSchema + Send + AsRef<[u8]>,
+    Body: From<T>,
+{
+    #[must_use]
+    pub fn with_strong_etag(mut self) -> Self {
+        self.etag = Some(ETag::strong_from_bytes(&self.data));
+        self
+    }
+
+    #[must_use]
+    pub fn with_weak_etag(mut self) -> Self {
+        self.etag = Some(ETag::weak_from_bytes(&self.data));
         self
     }
 }
@@ -44,15 +92,27 @@ where
     Body: From<T>,
 {
     fn into_response(self) -> axum::response::Response {
-        let body: Body = self.data.into();
-        let mut res = body.into_response();
-        if let Some(cookies) = self.cookies {
-            for cookie in cookies {
-                if let Ok(value) = <HeaderValue as TryFrom<String>>::try_from(cookie) {
-                    res.headers_mut().append(SET_COOKIE, value);
-                }
+        let precondition = self.etag.as_ref().and_then(|etag| {
+            self.if_none_match
+                .precondition_response(etag, self.status.unwrap_or(StatusCode::OK))
+        });
+        let mut res = if let Some(res) = precondition {
+            res
+        } else {
+            let mut res = Body::from(self.data).into_response();
+            if let Some(status) = self.status {
+                *res.status_mut() = status;
+                res.extensions_mut().insert(ExplicitStatus);
             }
+            res
+        };
+        if let Some(etag) = self.etag.as_ref().and_then(ETag::header_value) {
+            res.headers_mut().insert(ETAG, etag);
+        }
+        if let Some(cache_policy) = &self.cache_policy {
+            cache_policy.apply(res.headers_mut());
         }
+        self.cookies.apply(res.headers_mut());
         res
     }
 }
@@ -73,7 +133,7 @@ where
     Body: From<T>,
 {
     fn name() -> Cow<'static, str> {
-        T::name()
+        transparent_name::<T>()
     }
 
     fn schemas(
@@ -0,0 +1,78 @@
+use axum::body::{Body, Bytes};
+use axum::http::header::CONTENT_TYPE;
+use axum::http::header::HeaderValue;
+use axum::response::IntoResponse;
+use futures_util::{Stream, StreamExt, stream};
+use std::{borrow::Cow, convert::Infallible};
+use utoipa::PartialSchema;
+use utoipa::ToSchema;
+
+use crate::content_type_trait::{ContentTypeHtml, ContentTypeTrait};
+use crate::cookies::{Cookies, impl_with_cookie};
+
+pub struct HtmlStreamResponse<S> {
+    stream: S,
+    shell: Option<Bytes>,
+    cookies: Cookies,
+}
+
+impl<S, C> HtmlStreamResponse<S>
+where
+    S: Stream<Item = C> + Send + 'static,
+    C: Into<Bytes> + 'static,
+{
+    pub fn new(stream: S) -> Self {
+        Self {
+            stream,
+            shell: None,
+            cookies: Cookies::default(),
+        }
+    }
+
+    #[must_use]
+    pub fn with_shell(mut self, shell: impl Into<Bytes>) -> Self {
+        self.shell = Some(shell.into());
+        self
+    }
+
+    impl_with_cookie!();
+}
+
+impl<S, C> IntoResponse for HtmlStreamResponse<S>
+where
+    S: Stream<Item = C> + Send + 'static,
+    C: Into<Bytes> + 'static,
+{
+    fn into_response(self) -> axum::response::Response {
+        let chunks = stream::iter(self.shell)
+            .chain(self.stream.map(Into::into))
+            .map(Ok::<_, Infallible>);
+        let mut res = Body::from_stream(chunks).into_response();
+        res.headers_mut().insert(
+            CONTENT_TYPE,
+            HeaderValue::from_static(ContentTypeHtml::content_type_header()),
+        );
+        self.cookies.apply(res.headers_mut());
+        res
+    }
+}
+
+impl<S, C> PartialSchema for HtmlStreamResponse<S>
+where
+    S: Stream<Item = C> + Send + 'static,
+    C: Into<Bytes> + 'static,
+{
+    fn schema() -> utoipa::openapi::RefOr<utoipa::openapi::schema::Schema> {
+        String::schema()
+    }
+}
+
+impl<S, C> ToSchema for HtmlStreamResponse<S>
+where
+    S: Stream<Item = C> + Send + 'static,
+    C: Into<Bytes> + 'static,
+{
+    fn name() -> Cow<'static, str> {
+        String::name()
+    }
+}
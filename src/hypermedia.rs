@@ -0,0 +1,283 @@
+use axum::Json;
+use axum::http::StatusCode;
+use axum::http::header::CONTENT_TYPE;
+use axum::http::header::HeaderValue;
+use axum::response::IntoResponse;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::{borrow::Cow, collections::BTreeMap};
+use utoipa::openapi::content::ContentBuilder;
+use utoipa::openapi::schema::{AllOfBuilder, ObjectBuilder, Schema, Type};
+use utoipa::openapi::{RefOr, Response, ResponseBuilder, ResponsesBuilder};
+use utoipa::{IntoResponses, PartialSchema, ToSchema};
+
+use crate::content_type_trait::{ContentTypeHal, ContentTypeJsonApi, ContentTypeTrait};
+use crate::schema_name::wrapper_name;
+
+fn map_of(schema: impl Into<RefOr<Schema>>) -> ObjectBuilder {
+    ObjectBuilder::new()
+        .schema_type(Type::Object)
+        .additional_properties(Some(schema.into()))
+}
+
+fn json_response<T: Serialize>(content_type: &'static str, data: T) -> axum::response::Response {
+    let mut res = Json(data).into_response();
+    if res.status() == StatusCode::OK {
+        res.headers_mut()
+            .insert(CONTENT_TYPE, HeaderValue::from_static(content_type));
+    }
+    res
+}
+
+fn responses(
+    description: String,
+    content_type: &str,
+    schema: RefOr<Schema>,
+) -> BTreeMap<String, RefOr<Response>> {
+    let content = ContentBuilder::new().schema(Some(schema)).build();
+    ResponsesBuilder::new()
+        .response(
+            StatusCode::OK.as_str(),
+            ResponseBuilder::new()
+                .description(description)
+                .content(content_type, content),
+        )
+        .build()
+        .into()
+}
+
+/// A HAL link object.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct HalLink {
+    pub href: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub templated: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+}
+
+impl HalLink {
+    pub fn new(href: impl Into<String>) -> Self {
+        Self {
+            href: href.into(),
+            templated: None,
+            title: None,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Hal<T> {
+    #[serde(flatten)]
+    pub resource: T,
+    #[serde(rename = "_links")]
+    pub links: BTreeMap<String, HalLink>,
+    #[serde(
+        rename = "_embedded",
+        default,
+        skip_serializing_if = "BTreeMap::is_empty"
+    )]
+    pub embedded: BTreeMap<String, Value>,
+}
+
+impl<T> Hal<T> {
+    pub fn new(resource: T, self_href: impl Into<String>) -> Self {
+        Self {
+            resource,
+            links: BTreeMap::from([("self".to_string(), HalLink::new(self_href))]),
+            embedded: BTreeMap::new(),
+        }
+    }
+
+    #[must_use]
+    pub fn with_link(mut self, rel: impl Into<String>, link: HalLink) -> Self {
+        self.links.insert(rel.into(), link);
+        self
+    }
+
+    #[must_use]
+    pub fn with_embedded(mut self, rel: impl Into<String>, resource: Value) -> Self {
+        self.embedded.insert(rel.into(), resource);
+        self
+    }
+}
+
+impl<T> IntoResponse for Hal<T>
+where
+    T: Serialize,
+{
+    fn into_response(self) -> axum::response::Response {
+        json_response(ContentTypeHal::content_type(), self)
+    }
+}
+
+impl<T> IntoResponses for Hal<T>
+where
+    T: ToSchema + Serialize,
+{
+    fn responses() -> BTreeMap<String, RefOr<Response>> {
+        responses(
+            format!("HAL representation of {}", T::name()),
+            ContentTypeHal::content_type(),
+            Self::schema(),
+        )
+    }
+}
+
+impl<T> PartialSchema for Hal<T>
+where
+    T: ToSchema,
+{
+    fn schema() -> RefOr<Schema> {
+        let hal = ObjectBuilder::new()
+            .property("_links", map_of(HalLink::schema()))
+            .required("_links")
+            .property("_embedded", map_of(ObjectBuilder::new()));
+        AllOfBuilder::new().item(T::schema()).item(hal).into()
+    }
+}
+
+impl<T> ToSchema for Hal<T>
+where
+    T: ToSchema,
+{
+    fn name() -> Cow<'static, str> {
+        wrapper_name::<T>("Hal")
+    }
+
+    fn schemas(schemas: &mut Vec<(String, RefOr<Schema>)>) {
+        T::schemas(schemas);
+    }
+}
+
+/// A JSON:API resource identifier object.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct ResourceIdentifier {
+    #[serde(rename = "type")]
+    pub resource_type: String,
+    pub id: String,
+}
+
+impl ResourceIdentifier {
+    pub fn new(resource_type: impl Into<String>, id: impl Into<String>) -> Self {
+        Self {
+            resource_type: resource_type.into(),
+            id: id.into(),
+        }
+    }
+}
+
+/// The linkage of a JSON:API relationship, to-one or to-many.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, ToSchema)]
+#[serde(untagged)]
+pub enum RelationshipData {
+    Many(Vec<ResourceIdentifier>),
+    One(Option<ResourceIdentifier>),
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct Relationship {
+    pub data: RelationshipData,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct JsonApiResource<T> {
+    #[serde(rename = "type")]
+    pub resource_type: String,
+    pub id: String,
+    pub attributes: T,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub relationships: BTreeMap<String, Relationship>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct JsonApi<T> {
+    pub data: JsonApiResource<T>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub links: BTreeMap<String, String>,
+}
+
+impl<T> JsonApi<T> {
+    pub fn new(resource_type: impl Into<String>, id: impl Into<String>, attributes: T) -> Self {
+        Self {
+            data: JsonApiResource {
+                resource_type: resource_type.into(),
+                id: id.into(),
+                attributes,
+                relationships: BTreeMap::new(),
+            },
+            links: BTreeMap::new(),
+        }
+    }
+
+    #[must_use]
+    pub fn with_relationship(mut self, name: impl Into<String>, data: RelationshipData) -> Self {
+        self.data
+            .relationships
+            .insert(name.into(), Relationship { data });
+        self
+    }
+
+    #[must_use]
+    pub fn with_link(mut self, name: impl Into<String>, href: impl Into<String>) -> Self {
+        self.links.insert(name.into(), href.into());
+        self
+    }
+}
+
+impl<T> IntoResponse for JsonApi<T>
+where
+    T: Serialize,
+{
+    fn into_response(self) -> axum::response::Response {
+        json_response(ContentTypeJsonApi::content_type(), self)
+    }
+}
+
+impl<T> IntoResponses for JsonApi<T>
+where
+    T: ToSchema + Serialize,
+{
+    fn responses() -> BTreeMap<String, RefOr<Response>> {
+        responses(
+            format!("JSON:API document of {}", T::name()),
+            ContentTypeJsonApi::content_type(),
+            Self::schema(),
+        )
+    }
+}
+
+impl<T> PartialSchema for JsonApi<T>
+where
+    T: ToSchema,
+{
+    fn schema() -> RefOr<Schema> {
+        let resource = ObjectBuilder::new()
+            .property("type", String::schema())
+            .required("type")
+            .property("id", String::schema())
+            .required("id")
+            .property("attributes", T::schema())
+            .required("attributes")
+            .property("relationships", map_of(Relationship::schema()));
+        ObjectBuilder::new()
+            .property("data", resource)
+            .required("data")
+            .property("links", map_of(String::schema()))
+            .into()
+    }
+}
+
+impl<T> ToSchema for JsonApi<T>
+where
+    T: ToSchema,
+{
+    fn name() -> Cow<'static, str> {
+        wrapper_name::<T>("JsonApi")
+    }
+
+    fn schemas(schemas: &mut Vec<(String, RefOr<Schema>)>) {
+        T::schemas(schemas);
+        Relationship::schemas(schemas);
+    }
+}
@@ -0,0 +1,125 @@
+use axum::body::{Body, Bytes};
+use axum::http::header::CONTENT_TYPE;
+use axum::http::header::HeaderValue;
+use axum::response::IntoResponse;
+use futures_util::{Stream, StreamExt, future::ready, stream};
+use serde::Serialize;
+use std::{borrow::Cow, convert::Infallible};
+use utoipa::PartialSchema;
+use utoipa::ToSchema;
+
+use crate::content_type_trait::{ContentTypeJson, ContentTypeTrait};
+use crate::cookies::{Cookies, impl_with_cookie};
+
+const DEFAULT_BUFFER_SIZE: usize = 64;
+
+pub struct JsonArrayStreamResponse<S> {
+    stream: S,
+    buffer_size: usize,
+    cookies: Cookies,
+}
+
+impl<S, T> JsonArrayStreamResponse<S>
+where
+    S: Stream<Item = T> + Send + 'static,
+    T: Serialize + Send + 'static,
+    Vec<T>: ToSchema,
+{
+    pub fn new(stream: S) -> Self {
+        Self {
+            stream,
+            buffer_size: DEFAULT_BUFFER_SIZE,
+            cookies: Cookies::default(),
+        }
+    }
+
+    #[must_use]
+    pub fn with_buffer_size(mut self, buffer_size: usize) -> Self {
+        self.buffer_size = buffer_size.max(1);
+        self
+    }
+
+    impl_with_cookie!();
+}
+
+impl<S, T> IntoResponse for JsonArrayStreamResponse<S>
+where
+    S: Stream<Item = T> + Send + 'static,
+    T: Serialize + Send + 'static,
+    Vec<T>: ToSchema,
+{
+    fn into_response(self) -> axum::response::Response {
+        let body = self
+            .stream
+            .ready_chunks(self.buffer_size)
+            .map(Some)
+            .chain(stream::once(ready(None)))
+            .scan((true, false), |(first, closed), items| {
+                if *closed {
+                    return ready(None);
+                }
+                let mut buf = Vec::new();
+                let mut close = items.is_none();
+                for item in items.iter().flatten() {
+                    match serde_json::to_vec(item) {
+                        Ok(json) => {
+                            buf.push(if *first { b'[' } else { b',' });
+                            *first = false;
+                            buf.extend(json);
+                        }
+                        Err(error) => {
+                            tracing::error!(%error, "closing JSON array stream early");
+                            close = true;
+                            break;
+                        }
+                    }
+                }
+                if close {
+                    if *first {
+                        buf.push(b'[');
+                    }
+                    buf.push(b']');
+                    *closed = true;
+                }
+                ready(Some(Ok::<_, Infallible>(Bytes::from(buf))))
+            });
+        let mut res = Body::from_stream(body).into_response();
+        res.headers_mut().insert(
+            CONTENT_TYPE,
+            HeaderValue::from_static(ContentTypeJson::content_type()),
+        );
+        self.cookies.apply(res.headers_mut());
+        res
+    }
+}
+
+impl<S, T> PartialSchema for JsonArrayStreamResponse<S>
+where
+    S: Stream<Item = T> + Send + 'static,
+    T: Serialize + Send + 'static,
+    Vec<T>: ToSchema,
+{
+    fn schema() -> utoipa::openapi::RefOr<utoipa::openapi::schema::Schema> {
+        <Vec<T>>::schema()
+    }
+}
+
+impl<S, T> ToSchema for JsonArrayStreamResponse<S>
+where
+    S: Stream<Item = T> + Send + 'static,
+    T: Serialize + Send + 'static,
+    Vec<T>: ToSchema,
+{
+    fn name() -> Cow<'static, str> {
+        <Vec<T>>::name()
+    }
+
+    fn schemas(
+        schemas: &mut Vec<(
+            String,
+            utoipa::openapi::RefOr<utoipa::openapi::schema::Schema>,
+        )>,
+    ) {
+        <Vec<T>>::schemas(schemas);
+    }
+}
@@ -0,0 +1,241 @@
+use axum::body::Bytes;
+use axum::extract::{FromRequest, Request};
+use axum::http::HeaderMap;
+use axum::http::StatusCode;
+use axum::http::header::CONTENT_TYPE;
+use axum::response::IntoResponse;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use serde_json::error::Category;
+use std::{borrow::Cow, collections::BTreeMap, fmt};
+use utoipa::openapi::content::ContentBuilder;
+use utoipa::openapi::request_body::{RequestBody, RequestBodyBuilder};
+use utoipa::openapi::{RefOr, Required, Response};
+use utoipa::{IntoResponses, PartialSchema, ToSchema};
+
+use crate::content_type_trait::{ContentTypeJson, ContentTypeTrait};
+use crate::problem_details::{
+    ABOUT_BLANK, IntoProblemDetails, ProblemDetails, ProblemVariant, problem_responses,
+};
+use crate::schema_name::transparent_name;
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct JsonBody<T>(pub T);
+
+impl<T> JsonBody<T>
+where
+    T: ToSchema,
+{
+    pub fn request_body() -> RequestBody {
+        RequestBodyBuilder::new()
+            .content(
+                ContentTypeJson::content_type(),
+                ContentBuilder::new().schema(Some(T::schema())).build(),
+            )
+            .required(Some(Required::True))
+            .build()
+    }
+}
+
+fn is_json_content_type(headers: &HeaderMap) -> bool {
+    headers
+        .get(CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.split(';').next())
+        .map(str::trim)
+        .is_some_and(|mime| {
+            mime.eq_ignore_ascii_case(ContentTypeJson::content_type())
+                || (mime.starts_with("application/") && mime.ends_with("+json"))
+        })
+}
+
+impl<T> JsonBody<T>
+where
+    T: DeserializeOwned,
+{
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, JsonBodyRejection> {
+        let mut deserializer = serde_json::Deserializer::from_slice(bytes);
+        let value = serde_path_to_error::deserialize(&mut deserializer)
+            .map_err(JsonBodyRejection::from_path_error)?;
+        deserializer
+            .end()
+            .map_err(|e| JsonBodyRejection::from_json_error(String::new(), e))?;
+        Ok(Self(value))
+    }
+}
+
+impl<T> JsonBody<T>
+where
+    T: DeserializeOwned,
+{
+    pub fn from_value(value: Value) -> Result<Self, JsonBodyRejection> {
+        serde_path_to_error::deserialize(value)
+            .map(Self)
+            .map_err(JsonBodyRejection::from_path_error)
+    }
+}
+
+pub(crate) async fn json_bytes<S>(req: Request, state: &S) -> Result<Bytes, JsonBodyRejection>
+where
+    S: Send + Sync,
+{
+    if !is_json_content_type(req.headers()) {
+        return Err(JsonBodyRejection::MissingContentType);
+    }
+    Bytes::from_request(req, state)
+        .await
+        .map_err(|e| match e.status() {
+            StatusCode::PAYLOAD_TOO_LARGE => JsonBodyRejection::PayloadTooLarge,
+            _ => JsonBodyRejection::BodyRead(e.body_text()),
+        })
+}
+
+impl<T, S> FromRequest<S> for JsonBody<T>
+where
+    T: DeserializeOwned,
+    S: Send + Sync,
+{
+    type Rejection = JsonBodyRejection;
+
+    async fn from_request(req: Request, state: &S) -> Result<Self, Self::Rejection> {
+        let bytes = json_bytes(req, state).await?;
+        Self::from_bytes(&bytes)
+    }
+}
+
+impl<T> PartialSchema for JsonBody<T>
+where
+    T: ToSchema,
+{
+    fn schema() -> RefOr<utoipa::openapi::schema::Schema> {
+        T::schema()
+    }
+}
+
+impl<T> ToSchema for JsonBody<T>
+where
+    T: ToSchema,
+{
+    fn name() -> Cow<'static, str> {
+        transparent_name::<T>()
+    }
+
+    fn schemas(schemas: &mut Vec<(String, RefOr<utoipa::openapi::schema::Schema>)>) {
+        T::schemas(schemas);
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum JsonBodyRejection {
+    MissingContentType,
+    Syntax {
+        message: String,
+        line: usize,
+        column: usize,
+    },
+    Data {
+        path: String,
+        message: String,
+    },
+    PayloadTooLarge,
+    BodyRead(String),
+}
+
+impl JsonBodyRejection {
+    fn from_path_error(error: serde_path_to_error::Error<serde_json::Error>) -> Self {
+        let path = error.path().to_string();
+        Self::from_json_error(path, error.into_inner())
+    }
+
+    fn from_json_error(path: String, error: serde_json::Error) -> Self {
+        match error.classify() {
+            Category::Data => Self::Data {
+                path,
+                message: error.to_string(),
+            },
+            Category::Syntax | Category::Eof | Category::Io => Self::Syntax {
+                message: error.to_string(),
+                line: error.line(),
+                column: error.column(),
+            },
+        }
+    }
+
+    pub fn status_code(&self) -> StatusCode {
+        match self {
+            Self::MissingContentType => StatusCode::UNSUPPORTED_MEDIA_TYPE,
+            Self::Syntax { .. } | Self::BodyRead(_) => StatusCode::BAD_REQUEST,
+            Self::Data { .. } => StatusCode::UNPROCESSABLE_ENTITY,
+            Self::PayloadTooLarge => StatusCode::PAYLOAD_TOO_LARGE,
+        }
+    }
+}
+
+impl fmt::Display for JsonBodyRejection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingContentType => {
+                f.write_str("expected request with `Content-Type: application/json`")
+            }
+            Self::Syntax { message, .. } => write!(f, "invalid JSON: {message}"),
+            Self::Data { path, message } if path.is_empty() || path == "." => {
+                write!(f, "invalid request body: {message}")
+            }
+            Self::Data { path, message } => write!(f, "invalid field `{path}`: {message}"),
+            Self::PayloadTooLarge => f.write_str("request body is too large"),
+            Self::BodyRead(e) => write!(f, "failed to read request body: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for JsonBodyRejection {}
+
+impl IntoProblemDetails for JsonBodyRejection {
+    fn problem_details(&self) -> ProblemDetails {
+        let problem = ProblemDetails::new(self.status_code()).with_detail(self.to_string());
+        match self {
+            Self::Syntax { line, column, .. } => problem
+                .with_extension("line", *line)
+                .with_extension("column", *column),
+            Self::Data { path, .. } => problem.with_extension("path", path.as_str()),
+            _ => problem,
+        }
+    }
+}
+
+impl IntoResponse for JsonBodyRejection {
+    fn into_response(self) -> axum::response::Response {
+        self.problem_details().into_response()
+    }
+}
+
+impl IntoResponses for JsonBodyRejection {
+    fn responses() -> BTreeMap<String, RefOr<Response>> {
+        problem_responses(&[
+            ProblemVariant {
+                name: "Syntax",
+                status: StatusCode::BAD_REQUEST,
+                problem_type: ABOUT_BLANK,
+                title: Some("Malformed JSON body"),
+            },
+            ProblemVariant {
+                name: "PayloadTooLarge",
+                status: StatusCode::PAYLOAD_TOO_LARGE,
+                problem_type: ABOUT_BLANK,
+                title: Some("Request body too large"),
+            },
+            ProblemVariant {
+                name: "MissingContentType",
+                status: StatusCode::UNSUPPORTED_MEDIA_TYPE,
+                problem_type: ABOUT_BLANK,
+                title: Some("Expected application/json"),
+            },
+            ProblemVariant {
+                name: "Data",
+                status: StatusCode::UNPROCESSABLE_ENTITY,
+                problem_type: ABOUT_BLANK,
+                title: Some("Invalid request body"),
+            },
+        ])
+    }
+}
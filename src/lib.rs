@@ -1,10 +1,39 @@
+pub mod accept;
+pub mod accepted_response;
+pub mod cache_policy;
 pub mod content_type_trait;
+pub mod cookies;
+pub mod created_response;
+pub mod doc_params;
+pub mod empty_response;
+pub mod envelope;
+pub mod etag;
 pub mod html_response;
+pub mod html_stream_response;
+pub mod hypermedia;
+pub mod json_array_stream_response;
+pub mod json_body;
 pub mod json_response;
+pub mod ndjson_response;
+pub mod negotiated_error;
+pub mod negotiated_response;
+pub mod one_of;
+#[cfg(feature = "operations")]
+pub mod operation;
+pub mod pagination;
+pub mod problem_details;
+pub mod redirect_response;
 pub mod response_description_trait;
+pub mod schema_name;
+pub mod sse_response;
 pub mod status_code_trait;
+#[cfg(feature = "askama")]
+pub mod template_response;
+#[cfg(feature = "validation")]
+pub mod validation;
+pub mod variant_response;
 
-pub use utoipa_helper_macro::UtoipaResponse;
+pub use utoipa_helper_macro::{UtoipaError, UtoipaIntoResponse, UtoipaResponse};
 
 #[macro_export]
 macro_rules! derive_utoipa_schema {
@@ -18,7 +47,7 @@ macro_rules! derive_utoipa_schema {
         impl utoipa::ToSchema for $T0 {
             fn name() -> std::borrow::Cow<'static, str> {
                 assert_eq!(std::mem::size_of::<$T0>(), std::mem::size_of::<$T1>());
-                <$T1>::name()
+                $crate::schema_name::transparent_name::<$T1>()
             }
             fn schemas(
                 schemas: &mut Vec<(
@@ -0,0 +1,123 @@
+use axum::BoxError;
+use axum::body::{Body, Bytes};
+use axum::http::header::CONTENT_TYPE;
+use axum::http::header::HeaderValue;
+use axum::response::IntoResponse;
+use futures_util::{Stream, StreamExt, future::ready};
+use serde::Serialize;
+use std::borrow::Cow;
+use utoipa::PartialSchema;
+use utoipa::ToSchema;
+
+use crate::content_type_trait::{ContentTypeNdjson, ContentTypeTrait};
+use crate::cookies::{Cookies, impl_with_cookie};
+use crate::schema_name::transparent_name;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum NdjsonErrorMode {
+    #[default]
+    Abort,
+    ErrorLine,
+}
+
+pub struct NdjsonResponse<S> {
+    stream: S,
+    error_mode: NdjsonErrorMode,
+    cookies: Cookies,
+}
+
+impl<S, T, E> NdjsonResponse<S>
+where
+    S: Stream<Item = Result<T, E>> + Send + 'static,
+    T: ToSchema + Serialize + Send + 'static,
+    E: Into<BoxError> + Send + 'static,
+{
+    pub fn new(stream: S) -> Self {
+        Self {
+            stream,
+            error_mode: NdjsonErrorMode::default(),
+            cookies: Cookies::default(),
+        }
+    }
+
+    #[must_use]
+    pub fn with_error_mode(mut self, error_mode: NdjsonErrorMode) -> Self {
+        self.error_mode = error_mode;
+        self
+    }
+
+    impl_with_cookie!();
+}
+
+fn ndjson_line<T: Serialize>(data: &T) -> Result<Bytes, BoxError> {
+    let mut line = serde_json::to_vec(data)?;
+    line.push(b'\n');
+    Ok(line.into())
+}
+
+impl<S, T, E> IntoResponse for NdjsonResponse<S>
+where
+    S: Stream<Item = Result<T, E>> + Send + 'static,
+    T: ToSchema + Serialize + Send + 'static,
+    E: Into<BoxError> + Send + 'static,
+{
+    fn into_response(self) -> axum::response::Response {
+        let error_mode = self.error_mode;
+        let lines = self.stream.scan(false, move |done, item| {
+            if *done {
+                return ready(None);
+            }
+            let line = match item.map_err(Into::into).and_then(|data| ndjson_line(&data)) {
+                Ok(line) => Ok(line),
+                Err(error) => {
+                    *done = true;
+                    match error_mode {
+                        NdjsonErrorMode::Abort => Err(error),
+                        NdjsonErrorMode::ErrorLine => {
+                            ndjson_line(&serde_json::json!({"error": error.to_string()}))
+                        }
+                    }
+                }
+            };
+            ready(Some(line))
+        });
+        let mut res = Body::from_stream(lines).into_response();
+        res.headers_mut().insert(
+            CONTENT_TYPE,
+            HeaderValue::from_static(ContentTypeNdjson::content_type_header()),
+        );
+        self.cookies.apply(res.headers_mut());
+        res
+    }
+}
+
+impl<S, T, E> PartialSchema for NdjsonResponse<S>
+where
+    S: Stream<Item = Result<T, E>> + Send + 'static,
+    T: ToSchema + Serialize + Send + 'static,
+    E: Into<BoxError> + Send + 'static,
+{
+    fn schema() -> utoipa::openapi::RefOr<utoipa::openapi::schema::Schema> {
+        T::schema()
+    }
+}
+
+impl<S, T, E> ToSchema for NdjsonResponse<S>
+where
+    S: Stream<Item = Result<T, E>> + Send + 'static,
+    T: ToSchema + Serialize + Send + 'static,
+    E: Into<BoxError> + Send + 'static,
+{
+    fn name() -> Cow<'static, str> {
+        transparent_name::<T>()
+    }
+
+    fn schemas(
+        schemas: &mut Vec<(
+            String,
+            utoipa::openapi::RefOr<utoipa::openapi::schema::Schema>,
+        )>,
+    ) {
+        T::schemas(schemas);
+    }
+}
@@ -0,0 +1,116 @@
+use axum::body::Body;
+use axum::extract::Request;
+use axum::http::header::CONTENT_LENGTH;
+use axum::http::header::CONTENT_TYPE;
+use axum::http::header::HeaderValue;
+use axum::middleware::Next;
+use axum::response::{IntoResponse, Response};
+use std::collections::BTreeMap;
+use utoipa::IntoResponses;
+use utoipa::PartialSchema;
+use utoipa::openapi::RefOr;
+use utoipa::openapi::content::ContentBuilder;
+
+use crate::accept::Accept;
+use crate::content_type_trait::{ContentTypeHtml, ContentTypeProblemJson, ContentTypeTrait};
+use crate::problem_details::{IntoProblemDetails, ProblemDetails};
+
+#[derive(Debug)]
+pub struct NegotiatedError<E>(pub E);
+
+impl<E> From<E> for NegotiatedError<E>
+where
+    E: IntoProblemDetails + IntoResponses,
+{
+    fn from(item: E) -> Self {
+        Self(item)
+    }
+}
+
+impl<E> IntoResponse for NegotiatedError<E>
+where
+    E: IntoProblemDetails + IntoResponses,
+{
+    fn into_response(self) -> Response {
+        let problem = self.0.problem_details();
+        let mut res = problem.clone().into_response();
+        res.extensions_mut().insert(problem);
+        res
+    }
+}
+
+impl<E> IntoResponses for NegotiatedError<E>
+where
+    E: IntoProblemDetails + IntoResponses,
+{
+    fn responses() -> BTreeMap<String, RefOr<utoipa::openapi::Response>> {
+        let mut responses = E::responses();
+        for response in responses.values_mut() {
+            if let RefOr::T(response) = response {
+                let html = ContentBuilder::new().schema(Some(String::schema())).build();
+                response
+                    .content
+                    .insert(ContentTypeHtml::content_type().into(), html);
+            }
+        }
+        responses
+    }
+}
+
+fn escape_html(input: &str) -> String {
+    let mut output = String::with_capacity(input.len());
+    for c in input.chars() {
+        match c {
+            '&' => output.push_str("&amp;"),
+            '<' => output.push_str("&lt;"),
+            '>' => output.push_str("&gt;"),
+            '"' => output.push_str("&quot;"),
+            '\'' => output.push_str("&#39;"),
+            c => output.push(c),
+        }
+    }
+    output
+}
+
+pub fn html_error_page(problem: &ProblemDetails) -> String {
+    let status = problem.status_code();
+    let title = escape_html(
+        problem
+            .title
+            .as_deref()
+            .or(status.canonical_reason())
+            .unwrap_or("Error"),
+    );
+    let detail = problem
+        .detail
+        .as_deref()
+        .map(|detail| format!("<p>{}</p>", escape_html(detail)))
+        .unwrap_or_default();
+    format!(
+        "<!DOCTYPE html><html><head><meta charset=\"utf-8\"><title>{} {title}</title></head>\
+         <body><h1>{title}</h1>{detail}</body></html>",
+        status.as_u16(),
+    )
+}
+
+pub async fn negotiate_errors(request: Request, next: Next) -> Response {
+    let accept = Accept::from_headers(request.headers());
+    let mut res = next.run(request).await;
+    let preferred = accept.preferred(&[
+        ContentTypeProblemJson::content_type(),
+        "application/json",
+        ContentTypeHtml::content_type(),
+    ]);
+    if preferred != Some(ContentTypeHtml::content_type()) {
+        return res;
+    }
+    if let Some(problem) = res.extensions_mut().remove::<ProblemDetails>() {
+        *res.body_mut() = Body::from(html_error_page(&problem));
+        res.headers_mut().remove(CONTENT_LENGTH);
+        res.headers_mut().insert(
+            CONTENT_TYPE,
+            HeaderValue::from_static(ContentTypeHtml::content_type_header()),
+        );
+    }
+    res
+}
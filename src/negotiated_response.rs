@@ -0,0 +1,134 @@
+use axum::http::StatusCode;
+use axum::http::header::CONTENT_TYPE;
+use axum::http::header::HeaderValue;
+use axum::http::header::VARY;
+use axum::response::IntoResponse;
+use serde::Serialize;
+use std::{collections::BTreeMap, marker::PhantomData};
+use utoipa::openapi::content::ContentBuilder;
+use utoipa::openapi::{RefOr, Response, ResponseBuilder, ResponsesBuilder};
+use utoipa::{IntoResponses, PartialSchema, ToSchema};
+
+use crate::accept::Accept;
+use crate::content_type_trait::{ContentTypeProblemJson, ContentTypeTrait};
+use crate::cookies::{Cookies, impl_with_cookie};
+use crate::problem_details::ProblemDetails;
+use crate::response_description_trait::{DefaultDescription, ResponseDescriptionTrait};
+use crate::status_code_trait::{StatusCodeOk, StatusCodeTrait};
+
+pub fn supported_media_types() -> &'static [&'static str] {
+    &[
+        "application/json",
+        #[cfg(feature = "yaml")]
+        "application/yaml",
+        #[cfg(feature = "xml")]
+        "application/xml",
+        #[cfg(feature = "msgpack")]
+        "application/msgpack",
+    ]
+}
+
+pub struct NegotiatedResponse<T, S = StatusCodeOk, D = DefaultDescription>
+where
+    T: ToSchema + Serialize + Send,
+    S: StatusCodeTrait,
+    D: ResponseDescriptionTrait,
+{
+    data: T,
+    accept: Accept,
+    cookies: Cookies,
+    phantom: PhantomData<(S, D)>,
+}
+
+impl<T, S, D> NegotiatedResponse<T, S, D>
+where
+    T: ToSchema + Serialize + Send,
+    S: StatusCodeTrait,
+    D: ResponseDescriptionTrait,
+{
+    pub fn new(accept: Accept, data: T) -> Self {
+        Self {
+            data,
+            accept,
+            cookies: Cookies::default(),
+            phantom: PhantomData,
+        }
+    }
+
+    impl_with_cookie!();
+}
+
+fn serialize<T: Serialize>(media_type: &str, data: &T) -> Result<Vec<u8>, String> {
+    match media_type {
+        #[cfg(feature = "yaml")]
+        "application/yaml" => serde_yaml::to_string(data)
+            .map(String::into_bytes)
+            .map_err(|e| e.to_string()),
+        #[cfg(feature = "xml")]
+        "application/xml" => quick_xml::se::to_string(data)
+            .map(String::into_bytes)
+            .map_err(|e| e.to_string()),
+        #[cfg(feature = "msgpack")]
+        "application/msgpack" => rmp_serde::to_vec_named(data).map_err(|e| e.to_string()),
+        _ => serde_json::to_vec(data).map_err(|e| e.to_string()),
+    }
+}
+
+impl<T, S, D> IntoResponse for NegotiatedResponse<T, S, D>
+where
+    T: ToSchema + Serialize + Send,
+    S: StatusCodeTrait,
+    D: ResponseDescriptionTrait,
+{
+    fn into_response(self) -> axum::response::Response {
+        let supported = supported_media_types();
+        let Some(media_type) = self.accept.preferred(supported) else {
+            return ProblemDetails::new(StatusCode::NOT_ACCEPTABLE)
+                .with_detail(format!("supported media types: {}", supported.join(", ")))
+                .into_response();
+        };
+        let body = match serialize(media_type, &self.data) {
+            Ok(body) => body,
+            Err(e) => {
+                return ProblemDetails::new(StatusCode::INTERNAL_SERVER_ERROR)
+                    .with_detail(e)
+                    .into_response();
+            }
+        };
+        let mut res = (S::status_code(), body).into_response();
+        res.headers_mut()
+            .insert(CONTENT_TYPE, HeaderValue::from_static(media_type));
+        res.headers_mut()
+            .insert(VARY, HeaderValue::from_static("accept"));
+        self.cookies.apply(res.headers_mut());
+        res
+    }
+}
+
+impl<T, S, D> IntoResponses for NegotiatedResponse<T, S, D>
+where
+    T: ToSchema + Serialize + Send,
+    S: StatusCodeTrait,
+    D: ResponseDescriptionTrait,
+{
+    fn responses() -> BTreeMap<String, RefOr<Response>> {
+        let mut resp = ResponseBuilder::new().description(D::description());
+        for media_type in supported_media_types() {
+            let content = ContentBuilder::new().schema(Some(T::schema())).build();
+            resp = resp.content(*media_type, content);
+        }
+        let not_acceptable = ResponseBuilder::new()
+            .description("No supported media type is acceptable")
+            .content(
+                ContentTypeProblemJson::content_type(),
+                ContentBuilder::new()
+                    .schema(Some(ProblemDetails::schema()))
+                    .build(),
+            );
+        ResponsesBuilder::new()
+            .response(S::response_key(), resp)
+            .response(StatusCode::NOT_ACCEPTABLE.as_str(), not_acceptable)
+            .build()
+            .into()
+    }
+}
@@ -0,0 +1,130 @@
+use axum::response::{IntoResponse, Response};
+use std::{collections::BTreeMap, fmt};
+use utoipa::IntoResponses;
+use utoipa::openapi::RefOr;
+
+type Responses = BTreeMap<String, RefOr<utoipa::openapi::Response>>;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ResponseConflict {
+    pub status: String,
+    pub content_type: Option<String>,
+}
+
+impl fmt::Display for ResponseConflict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.content_type {
+            Some(content_type) => write!(
+                f,
+                "conflicting {content_type} content documented for status {}",
+                self.status
+            ),
+            None => write!(
+                f,
+                "conflicting responses documented for status {}",
+                self.status
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ResponseConflict {}
+
+pub fn merge_responses(
+    responses: &mut Responses,
+    other: Responses,
+) -> Result<(), ResponseConflict> {
+    let mut conflict = None;
+    for (status, response) in other {
+        let Some(existing) = responses.get_mut(&status) else {
+            responses.insert(status, response);
+            continue;
+        };
+        if *existing == response {
+            continue;
+        }
+        let (RefOr::T(existing), RefOr::T(response)) = (existing, response) else {
+            conflict.get_or_insert(ResponseConflict {
+                status,
+                content_type: None,
+            });
+            continue;
+        };
+        for (content_type, content) in response.content {
+            match existing.content.get(&content_type) {
+                Some(existing_content) if *existing_content != content => {
+                    conflict.get_or_insert(ResponseConflict {
+                        status: status.clone(),
+                        content_type: Some(content_type),
+                    });
+                }
+                Some(_) => {}
+                None => {
+                    existing.content.insert(content_type, content);
+                }
+            }
+        }
+        existing.headers.extend(response.headers);
+        if !response.description.is_empty() && existing.description != response.description {
+            if existing.description.is_empty() {
+                existing.description = response.description;
+            } else {
+                existing.description =
+                    format!("{} or {}", existing.description, response.description);
+            }
+        }
+    }
+    conflict.map_or(Ok(()), Err)
+}
+
+macro_rules! derive_one_of {
+    ($name:ident, $($variant:ident),+) => {
+        pub enum $name<$($variant),+> {
+            $($variant($variant)),+
+        }
+
+        impl<$($variant),+> IntoResponse for $name<$($variant),+>
+        where
+            $($variant: IntoResponse),+
+        {
+            fn into_response(self) -> Response {
+                match self {
+                    $(Self::$variant(response) => response.into_response()),+
+                }
+            }
+        }
+
+        impl<$($variant),+> $name<$($variant),+>
+        where
+            $($variant: IntoResponses),+
+        {
+            pub fn try_responses() -> Result<Responses, ResponseConflict> {
+                let mut responses = Responses::new();
+                let mut conflict = None;
+                $(
+                    if let Err(e) = merge_responses(&mut responses, $variant::responses()) {
+                        conflict.get_or_insert(e);
+                    }
+                )+
+                conflict.map_or(Ok(responses), Err)
+            }
+        }
+
+        impl<$($variant),+> IntoResponses for $name<$($variant),+>
+        where
+            $($variant: IntoResponses),+
+        {
+            fn responses() -> Responses {
+                Self::try_responses().unwrap_or_else(|e| panic!("{e}"))
+            }
+        }
+    };
+}
+
+derive_one_of!(OneOf2, A, B);
+derive_one_of!(OneOf3, A, B, C);
+derive_one_of!(OneOf4, A, B, C, D);
+derive_one_of!(OneOf5, A, B, C, D, E);
+derive_one_of!(OneOf6, A, B, C, D, E, F);
+derive_one_of!(OneOf7, A, B, C, D, E, F, G);
+derive_one_of!(OneOf8, A, B, C, D, E, F, G, H);
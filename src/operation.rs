@@ -0,0 +1,267 @@
+use axum::Json;
+use axum::extract::Path;
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+use serde::{Deserialize, Serialize};
+use std::{
+    borrow::Cow,
+    collections::HashMap,
+    sync::{Arc, Mutex, PoisonError},
+    time::{Duration, Instant},
+};
+use utoipa::PartialSchema;
+use utoipa::ToSchema;
+use utoipa::openapi::HttpMethod;
+use utoipa::openapi::content::ContentBuilder;
+use utoipa::openapi::path::{
+    OperationBuilder, ParameterBuilder, ParameterIn, PathItem, PathsBuilder,
+};
+use utoipa::openapi::schema::{ObjectBuilder, OneOfBuilder, Schema, Type};
+use utoipa::openapi::{RefOr, Required, ResponseBuilder, ResponsesBuilder};
+use utoipa_axum::router::OpenApiRouter;
+
+use crate::accepted_response::AcceptedResponse;
+use crate::content_type_trait::{ContentTypeJson, ContentTypeTrait};
+use crate::problem_details::{ABOUT_BLANK, ProblemDetails, ProblemVariant, problem_responses};
+use crate::schema_name::wrapper_name;
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum OperationStatus<T> {
+    Pending,
+    Running,
+    Succeeded { result: T },
+    Failed { problem: ProblemDetails },
+}
+
+impl<T> OperationStatus<T> {
+    pub fn is_terminal(&self) -> bool {
+        matches!(self, Self::Succeeded { .. } | Self::Failed { .. })
+    }
+}
+
+fn status_variant(status: &str) -> ObjectBuilder {
+    ObjectBuilder::new()
+        .property(
+            "status",
+            ObjectBuilder::new()
+                .schema_type(Type::String)
+                .enum_values(Some([status])),
+        )
+        .required("status")
+}
+
+impl<T> PartialSchema for OperationStatus<T>
+where
+    T: ToSchema,
+{
+    fn schema() -> RefOr<Schema> {
+        OneOfBuilder::new()
+            .item(status_variant("pending"))
+            .item(status_variant("running"))
+            .item(
+                status_variant("succeeded")
+                    .property("result", T::schema())
+                    .required("result"),
+            )
+            .item(
+                status_variant("failed")
+                    .property("problem", ProblemDetails::schema())
+                    .required("problem"),
+            )
+            .into()
+    }
+}
+
+impl<T> ToSchema for OperationStatus<T>
+where
+    T: ToSchema,
+{
+    fn name() -> Cow<'static, str> {
+        wrapper_name::<T>("OperationStatus")
+    }
+
+    fn schemas(schemas: &mut Vec<(String, RefOr<Schema>)>) {
+        T::schemas(schemas);
+    }
+}
+
+struct Operation<T> {
+    status: OperationStatus<T>,
+    finished: Option<Instant>,
+}
+
+impl<T> Operation<T> {
+    fn new(status: OperationStatus<T>) -> Self {
+        let finished = status.is_terminal().then(Instant::now);
+        Self { status, finished }
+    }
+
+    fn expired(&self, ttl: Duration) -> bool {
+        self.finished
+            .is_some_and(|finished| finished.elapsed() >= ttl)
+    }
+}
+
+pub struct OperationRegistry<T> {
+    base_path: Arc<str>,
+    ttl: Duration,
+    operations: Arc<Mutex<HashMap<String, Operation<T>>>>,
+}
+
+impl<T> Clone for OperationRegistry<T> {
+    fn clone(&self) -> Self {
+        Self {
+            base_path: self.base_path.clone(),
+            ttl: self.ttl,
+            operations: self.operations.clone(),
+        }
+    }
+}
+
+impl<T> Default for OperationRegistry<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> OperationRegistry<T> {
+    pub fn new() -> Self {
+        Self::with_base_path("/operations")
+    }
+
+    pub fn with_base_path(base_path: impl AsRef<str>) -> Self {
+        Self {
+            base_path: base_path.as_ref().trim_end_matches('/').into(),
+            ttl: Duration::from_secs(3600),
+            operations: Arc::default(),
+        }
+    }
+
+    #[must_use]
+    pub fn with_ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = ttl;
+        self
+    }
+
+    pub fn start(&self) -> String {
+        let id = uuid::Uuid::new_v4().to_string();
+        let mut operations = self.lock();
+        operations.retain(|_, operation| !operation.expired(self.ttl));
+        operations.insert(id.clone(), Operation::new(OperationStatus::Pending));
+        id
+    }
+
+    pub fn operation_id(&self) -> String {
+        let name: Vec<_> = self
+            .base_path
+            .split(|c: char| !c.is_ascii_alphanumeric())
+            .filter(|part| !part.is_empty())
+            .collect();
+        format!("get_{}_status", name.join("_"))
+    }
+
+    pub fn location(&self, id: &str) -> String {
+        format!("{}/{id}", self.base_path)
+    }
+
+    pub fn update(&self, id: &str, status: OperationStatus<T>) -> bool {
+        match self.lock().get_mut(id) {
+            Some(existing) if !existing.expired(self.ttl) => {
+                *existing = Operation::new(status);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    pub fn remove(&self, id: &str) -> Option<OperationStatus<T>> {
+        self.lock()
+            .remove(id)
+            .filter(|operation| !operation.expired(self.ttl))
+            .map(|operation| operation.status)
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, HashMap<String, Operation<T>>> {
+        self.operations
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl<T> OperationRegistry<T>
+where
+    T: Clone,
+{
+    pub fn get(&self, id: &str) -> Option<OperationStatus<T>> {
+        self.lock()
+            .get(id)
+            .filter(|operation| !operation.expired(self.ttl))
+            .map(|operation| operation.status.clone())
+    }
+}
+
+impl<T> OperationRegistry<T>
+where
+    T: ToSchema + Serialize + Clone + Send + Sync + 'static,
+{
+    pub fn accepted(&self) -> (String, AcceptedResponse<OperationStatus<T>>) {
+        let id = self.start();
+        let location = self.location(&id);
+        (
+            id,
+            AcceptedResponse::new(OperationStatus::Pending, location),
+        )
+    }
+
+    pub fn router<S>(&self) -> OpenApiRouter<S>
+    where
+        S: Clone + Send + Sync + 'static,
+    {
+        let path = format!("{}/{{id}}", self.base_path);
+        let registry = self.clone();
+        let handler = axum::routing::get(move |Path(id): Path<String>| async move {
+            match registry.get(&id) {
+                Some(status) => Json(status).into_response(),
+                None => ProblemDetails::new(StatusCode::NOT_FOUND)
+                    .with_detail(format!("operation {id} not found"))
+                    .into_response(),
+            }
+        });
+
+        let ok = ResponseBuilder::new()
+            .description("Current state of the operation")
+            .content(
+                ContentTypeJson::content_type(),
+                ContentBuilder::new()
+                    .schema(Some(OperationStatus::<T>::schema()))
+                    .build(),
+            );
+        let not_found = problem_responses(&[ProblemVariant {
+            name: "NotFound",
+            status: StatusCode::NOT_FOUND,
+            problem_type: ABOUT_BLANK,
+            title: Some("Operation not found"),
+        }]);
+        let operation = OperationBuilder::new()
+            .operation_id(Some(self.operation_id()))
+            .parameter(
+                ParameterBuilder::new()
+                    .name("id")
+                    .parameter_in(ParameterIn::Path)
+                    .required(Required::True)
+                    .schema(Some(String::schema())),
+            )
+            .responses(
+                ResponsesBuilder::new()
+                    .response(StatusCode::OK.as_str(), ok)
+                    .responses_from_iter(not_found),
+            );
+        let paths = PathsBuilder::new()
+            .path(path, PathItem::new(HttpMethod::Get, operation))
+            .build();
+        let mut schemas = Vec::new();
+        OperationStatus::<T>::schemas(&mut schemas);
+        OpenApiRouter::new().routes((schemas, paths, handler))
+    }
+}
@@ -0,0 +1,363 @@
+use axum::extract::{FromRequestParts, Query};
+use axum::http::StatusCode;
+use axum::http::header::HeaderValue;
+use axum::http::header::LINK;
+use axum::http::request::Parts;
+use axum::response::IntoResponse;
+use serde::{Deserialize, Serialize};
+use std::{borrow::Cow, collections::BTreeMap, fmt};
+use utoipa::openapi::content::ContentBuilder;
+use utoipa::openapi::header::HeaderBuilder;
+use utoipa::openapi::path::{Parameter, ParameterBuilder, ParameterIn};
+use utoipa::openapi::schema::{ArrayBuilder, ObjectBuilder, Schema, Type};
+use utoipa::openapi::{RefOr, Response, ResponseBuilder, ResponsesBuilder};
+use utoipa::{IntoParams, IntoResponses, PartialSchema, ToSchema};
+
+use crate::content_type_trait::{ContentTypeJson, ContentTypeTrait};
+use crate::cookies::{Cookies, impl_with_cookie};
+use crate::json_response::JsonResponse;
+use crate::problem_details::{
+    ABOUT_BLANK, IntoProblemDetails, ProblemDetails, ProblemVariant, problem_responses,
+};
+use crate::schema_name::wrapper_name;
+
+pub const DEFAULT_PAGE_LIMIT: u32 = 20;
+pub const MAX_PAGE_LIMIT: u32 = 100;
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Page<T> {
+    pub items: Vec<T>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub total: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub next: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub prev: Option<String>,
+}
+
+impl<T> Page<T> {
+    pub fn new(items: Vec<T>) -> Self {
+        Self {
+            items,
+            total: None,
+            next: None,
+            prev: None,
+        }
+    }
+
+    #[must_use]
+    pub fn with_total(mut self, total: u64) -> Self {
+        self.total = Some(total);
+        self
+    }
+
+    #[must_use]
+    pub fn with_next(mut self, next: impl Into<String>) -> Self {
+        self.next = Some(next.into());
+        self
+    }
+
+    #[must_use]
+    pub fn with_prev(mut self, prev: impl Into<String>) -> Self {
+        self.prev = Some(prev.into());
+        self
+    }
+}
+
+impl<T> PartialSchema for Page<T>
+where
+    T: ToSchema,
+{
+    fn schema() -> RefOr<Schema> {
+        let string = || ObjectBuilder::new().schema_type(Type::String);
+        ObjectBuilder::new()
+            .property("items", ArrayBuilder::new().items(T::schema()))
+            .required("items")
+            .property(
+                "total",
+                ObjectBuilder::new()
+                    .schema_type(Type::Integer)
+                    .minimum(Some(0))
+                    .description(Some("Total number of items")),
+            )
+            .property("next", string().description(Some("URL of the next page")))
+            .property(
+                "prev",
+                string().description(Some("URL of the previous page")),
+            )
+            .into()
+    }
+}
+
+impl<T> ToSchema for Page<T>
+where
+    T: ToSchema,
+{
+    fn name() -> Cow<'static, str> {
+        wrapper_name::<T>("Page")
+    }
+
+    fn schemas(schemas: &mut Vec<(String, RefOr<Schema>)>) {
+        T::schemas(schemas);
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct PageParams {
+    pub cursor: Option<String>,
+    pub offset: Option<u64>,
+    pub limit: Option<u32>,
+}
+
+impl PageParams {
+    pub fn offset(&self) -> u64 {
+        self.offset.unwrap_or(0)
+    }
+
+    pub fn limit(&self) -> u32 {
+        self.limit.unwrap_or(DEFAULT_PAGE_LIMIT)
+    }
+
+    pub fn validate(&self) -> Result<(), PageParamsError> {
+        if self.cursor.is_some() && self.offset.is_some() {
+            return Err(PageParamsError::CursorAndOffset);
+        }
+        match self.limit {
+            Some(limit) if limit == 0 || limit > MAX_PAGE_LIMIT => {
+                Err(PageParamsError::Limit(limit))
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+impl<S> FromRequestParts<S> for PageParams
+where
+    S: Send + Sync,
+{
+    type Rejection = PageParamsError;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        let Query(params) = Query::<Self>::from_request_parts(parts, state)
+            .await
+            .map_err(|e| PageParamsError::Query(e.body_text()))?;
+        params.validate()?;
+        Ok(params)
+    }
+}
+
+impl IntoParams for PageParams {
+    fn into_params(_: impl Fn() -> Option<ParameterIn>) -> Vec<Parameter> {
+        vec![
+            ParameterBuilder::new()
+                .name("cursor")
+                .parameter_in(ParameterIn::Query)
+                .description(Some("Opaque cursor from a previous page"))
+                .schema(Some(String::schema()))
+                .build(),
+            ParameterBuilder::new()
+                .name("offset")
+                .parameter_in(ParameterIn::Query)
+                .description(Some("Number of items to skip, not combined with cursor"))
+                .schema(Some(
+                    ObjectBuilder::new()
+                        .schema_type(Type::Integer)
+                        .minimum(Some(0)),
+                ))
+                .build(),
+            ParameterBuilder::new()
+                .name("limit")
+                .parameter_in(ParameterIn::Query)
+                .description(Some("Maximum number of items to return"))
+                .schema(Some(
+                    ObjectBuilder::new()
+                        .schema_type(Type::Integer)
+                        .minimum(Some(1))
+                        .maximum(Some(MAX_PAGE_LIMIT))
+                        .default(Some(DEFAULT_PAGE_LIMIT.into())),
+                ))
+                .build(),
+        ]
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum PageParamsError {
+    Query(String),
+    Limit(u32),
+    CursorAndOffset,
+}
+
+impl fmt::Display for PageParamsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Query(e) => write!(f, "{e}"),
+            Self::Limit(limit) => {
+                write!(f, "limit {limit} is not between 1 and {MAX_PAGE_LIMIT}")
+            }
+            Self::CursorAndOffset => f.write_str("cursor and offset cannot be combined"),
+        }
+    }
+}
+
+impl std::error::Error for PageParamsError {}
+
+impl IntoProblemDetails for PageParamsError {
+    fn problem_details(&self) -> ProblemDetails {
+        ProblemDetails::new(StatusCode::BAD_REQUEST).with_detail(self.to_string())
+    }
+}
+
+impl IntoResponse for PageParamsError {
+    fn into_response(self) -> axum::response::Response {
+        self.problem_details().into_response()
+    }
+}
+
+impl IntoResponses for PageParamsError {
+    fn responses() -> BTreeMap<String, RefOr<Response>> {
+        problem_responses(&[ProblemVariant {
+            name: "InvalidPage",
+            status: StatusCode::BAD_REQUEST,
+            problem_type: ABOUT_BLANK,
+            title: Some("Invalid page parameters"),
+        }])
+    }
+}
+
+pub struct PaginatedResponse<T>
+where
+    T: ToSchema + Serialize + Send,
+{
+    page: Page<T>,
+    first: Option<String>,
+    last: Option<String>,
+    cookies: Cookies,
+}
+
+impl<T> PaginatedResponse<T>
+where
+    T: ToSchema + Serialize + Send,
+{
+    pub fn new(page: Page<T>) -> Self {
+        Self {
+            page,
+            first: None,
+            last: None,
+            cookies: Cookies::default(),
+        }
+    }
+
+    pub fn from_offset(items: Vec<T>, total: u64, params: &PageParams, base_path: &str) -> Self {
+        let limit = u64::from(params.limit().max(1));
+        let offset = params.offset();
+        let separator = if base_path.contains('?') { '&' } else { '?' };
+        let link = |offset: u64| format!("{base_path}{separator}offset={offset}&limit={limit}");
+        let last_offset = total.saturating_sub(1) / limit * limit;
+        let mut page = Page::new(items).with_total(total);
+        if offset + limit < total {
+            page = page.with_next(link(offset + limit));
+        }
+        if offset > 0 {
+            page = page.with_prev(link(offset.saturating_sub(limit)));
+        }
+        Self::new(page)
+            .with_first(link(0))
+            .with_last(link(last_offset))
+    }
+
+    #[must_use]
+    pub fn with_first(mut self, first: impl Into<String>) -> Self {
+        self.first = Some(first.into());
+        self
+    }
+
+    #[must_use]
+    pub fn with_last(mut self, last: impl Into<String>) -> Self {
+        self.last = Some(last.into());
+        self
+    }
+
+    impl_with_cookie!();
+
+    pub fn link_header(&self) -> Option<String> {
+        let links: Vec<_> = [
+            ("next", &self.page.next),
+            ("prev", &self.page.prev),
+            ("first", &self.first),
+            ("last", &self.last),
+        ]
+        .into_iter()
+        .filter_map(|(rel, url)| Some(format!("<{}>; rel=\"{rel}\"", url.as_ref()?)))
+        .collect();
+        if links.is_empty() {
+            None
+        } else {
+            Some(links.join(", "))
+        }
+    }
+}
+
+impl<T> IntoResponse for PaginatedResponse<T>
+where
+    T: ToSchema + Serialize + Send,
+{
+    fn into_response(self) -> axum::response::Response {
+        let link = self.link_header();
+        let mut res = JsonResponse::new(self.page).into_response();
+        self.cookies.apply(res.headers_mut());
+        if let Some(value) = link.and_then(|link| HeaderValue::try_from(link).ok()) {
+            res.headers_mut().insert(LINK, value);
+        }
+        res
+    }
+}
+
+impl<T> IntoResponses for PaginatedResponse<T>
+where
+    T: ToSchema + Serialize + Send,
+{
+    fn responses() -> BTreeMap<String, RefOr<Response>> {
+        let link = HeaderBuilder::new()
+            .schema(String::schema())
+            .description(Some(
+                "RFC 8288 links to the next, prev, first and last pages",
+            ))
+            .build();
+        let content = ContentBuilder::new()
+            .schema(Some(Page::<T>::schema()))
+            .build();
+        ResponsesBuilder::new()
+            .response(
+                StatusCode::OK.as_str(),
+                ResponseBuilder::new()
+                    .description(format!("A page of {}", T::name()))
+                    .header("Link", link)
+                    .content(ContentTypeJson::content_type(), content),
+            )
+            .build()
+            .into()
+    }
+}
+
+impl<T> PartialSchema for PaginatedResponse<T>
+where
+    T: ToSchema + Serialize + Send,
+{
+    fn schema() -> RefOr<Schema> {
+        Page::<T>::schema()
+    }
+}
+
+impl<T> ToSchema for PaginatedResponse<T>
+where
+    T: ToSchema + Serialize + Send,
+{
+    fn name() -> Cow<'static, str> {
+        Page::<T>::name()
+    }
+
+    fn schemas(schemas: &mut Vec<(String, RefOr<Schema>)>) {
+        Page::<T>::schemas(schemas);
+    }
+}
@@ -0,0 +1,149 @@
+use axum::Json;
+use axum::http::StatusCode;
+use axum::http::header::CONTENT_TYPE;
+use axum::http::header::HeaderValue;
+use axum::response::IntoResponse;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::BTreeMap;
+use utoipa::openapi::content::ContentBuilder;
+use utoipa::openapi::example::ExampleBuilder;
+use utoipa::openapi::{RefOr, Response, ResponseBuilder};
+use utoipa::{PartialSchema, ToSchema};
+
+use crate::content_type_trait::{ContentTypeProblemJson, ContentTypeTrait};
+
+pub const ABOUT_BLANK: &str = "about:blank";
+
+/// RFC 9457 problem details, sent as `application/problem+json`.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct ProblemDetails {
+    /// URI reference identifying the problem type
+    #[serde(rename = "type", default = "about_blank")]
+    #[schema(default = "about:blank")]
+    pub problem_type: String,
+    /// Short, human-readable summary of the problem type
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// HTTP status code generated by the origin server
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<u16>,
+    /// Human-readable explanation specific to this occurrence
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub detail: Option<String>,
+    /// URI reference identifying this occurrence
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub instance: Option<String>,
+    /// Additional problem type specific members
+    #[serde(flatten)]
+    #[schema(ignore)]
+    pub extensions: BTreeMap<String, Value>,
+}
+
+fn about_blank() -> String {
+    ABOUT_BLANK.into()
+}
+
+impl ProblemDetails {
+    pub fn new(status: StatusCode) -> Self {
+        Self {
+            problem_type: about_blank(),
+            title: status.canonical_reason().map(Into::into),
+            status: Some(status.as_u16()),
+            detail: None,
+            instance: None,
+            extensions: BTreeMap::new(),
+        }
+    }
+
+    #[must_use]
+    pub fn with_type(mut self, problem_type: impl Into<String>) -> Self {
+        self.problem_type = problem_type.into();
+        self
+    }
+
+    #[must_use]
+    pub fn with_title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    #[must_use]
+    pub fn with_detail(mut self, detail: impl Into<String>) -> Self {
+        self.detail = Some(detail.into());
+        self
+    }
+
+    #[must_use]
+    pub fn with_instance(mut self, instance: impl Into<String>) -> Self {
+        self.instance = Some(instance.into());
+        self
+    }
+
+    #[must_use]
+    pub fn with_extension(mut self, key: impl Into<String>, value: impl Into<Value>) -> Self {
+        self.extensions.insert(key.into(), value.into());
+        self
+    }
+
+    pub fn status_code(&self) -> StatusCode {
+        self.status
+            .and_then(|s| StatusCode::from_u16(s).ok())
+            .unwrap_or(StatusCode::INTERNAL_SERVER_ERROR)
+    }
+}
+
+impl IntoResponse for ProblemDetails {
+    fn into_response(self) -> axum::response::Response {
+        let status = self.status_code();
+        let mut res = (status, Json(self)).into_response();
+        res.headers_mut().insert(
+            CONTENT_TYPE,
+            HeaderValue::from_static(ContentTypeProblemJson::content_type()),
+        );
+        res
+    }
+}
+
+pub trait IntoProblemDetails {
+    fn problem_details(&self) -> ProblemDetails;
+}
+
+pub struct ProblemVariant {
+    pub name: &'static str,
+    pub status: StatusCode,
+    pub problem_type: &'static str,
+    pub title: Option<&'static str>,
+}
+
+pub fn problem_responses(variants: &[ProblemVariant]) -> BTreeMap<String, RefOr<Response>> {
+    let mut by_status: BTreeMap<StatusCode, Vec<&ProblemVariant>> = BTreeMap::new();
+    for variant in variants {
+        by_status.entry(variant.status).or_default().push(variant);
+    }
+    by_status
+        .into_iter()
+        .map(|(status, variants)| {
+            let mut content = ContentBuilder::new().schema(Some(ProblemDetails::schema()));
+            let mut titles = Vec::new();
+            for variant in variants {
+                let mut problem = ProblemDetails::new(status).with_type(variant.problem_type);
+                if let Some(title) = variant.title {
+                    problem = problem.with_title(title);
+                }
+                if let Some(title) = problem.title.as_ref().filter(|t| !titles.contains(*t)) {
+                    titles.push(title.clone());
+                }
+                let example = ExampleBuilder::new()
+                    .value(serde_json::to_value(&problem).ok())
+                    .build();
+                content = content.examples_from_iter([(variant.name, example)]);
+            }
+            let response = ResponseBuilder::new()
+                .description(titles.join("; "))
+                .content(ContentTypeProblemJson::content_type(), content.build())
+                .build();
+            (status.as_str().to_string(), response.into())
+        })
+        .collect()
+}
@@ -0,0 +1,87 @@
+use axum::http::StatusCode;
+use axum::http::header::HeaderValue;
+use axum::http::header::LOCATION;
+use axum::response::IntoResponse;
+use std::{collections::BTreeMap, convert::TryFrom, marker::PhantomData};
+use utoipa::openapi::header::HeaderBuilder;
+use utoipa::openapi::{RefOr, Response, ResponseBuilder, ResponsesBuilder};
+use utoipa::{IntoResponses, PartialSchema};
+
+use crate::cookies::{Cookies, impl_with_cookie};
+use crate::response_description_trait::{DefaultDescription, ResponseDescriptionTrait};
+use crate::status_code_trait::{StatusCodeTrait, StatusCodeValue};
+
+pub struct RedirectResponse<S, D = DefaultDescription>
+where
+    S: StatusCodeTrait,
+    D: ResponseDescriptionTrait,
+{
+    location: String,
+    cookies: Cookies,
+    phantom: PhantomData<(S, D)>,
+}
+
+pub type FoundResponse<D = DefaultDescription> = RedirectResponse<StatusCodeValue<302>, D>;
+pub type SeeOtherResponse<D = DefaultDescription> = RedirectResponse<StatusCodeValue<303>, D>;
+pub type TemporaryRedirectResponse<D = DefaultDescription> =
+    RedirectResponse<StatusCodeValue<307>, D>;
+pub type PermanentRedirectResponse<D = DefaultDescription> =
+    RedirectResponse<StatusCodeValue<308>, D>;
+
+impl<S, D> RedirectResponse<S, D>
+where
+    S: StatusCodeTrait,
+    D: ResponseDescriptionTrait,
+{
+    pub fn new(location: impl Into<String>) -> Self {
+        Self {
+            location: location.into(),
+            cookies: Cookies::default(),
+            phantom: PhantomData,
+        }
+    }
+
+    pub fn location(&self) -> &str {
+        &self.location
+    }
+
+    impl_with_cookie!();
+}
+
+impl<S, D> IntoResponse for RedirectResponse<S, D>
+where
+    S: StatusCodeTrait,
+    D: ResponseDescriptionTrait,
+{
+    fn into_response(self) -> axum::response::Response {
+        let Ok(location) = <HeaderValue as TryFrom<String>>::try_from(self.location) else {
+            return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+        };
+        let mut res = S::status_code().into_response();
+        res.headers_mut().insert(LOCATION, location);
+        self.cookies.apply(res.headers_mut());
+        res
+    }
+}
+
+impl<S, D> IntoResponses for RedirectResponse<S, D>
+where
+    S: StatusCodeTrait,
+    D: ResponseDescriptionTrait,
+{
+    fn responses() -> BTreeMap<String, RefOr<Response>> {
+        let location = HeaderBuilder::new()
+            .schema(String::schema())
+            .description(Some("Redirect target"))
+            .build();
+        ResponsesBuilder::new()
+            .response(
+                S::response_key(),
+                ResponseBuilder::new()
+                    .description(D::description())
+                    .header("Location", location),
+            )
+            .build()
+            .into()
+    }
+}
@@ -0,0 +1,220 @@
+use std::sync::OnceLock;
+use std::{borrow::Cow, collections::BTreeMap, fmt};
+use utoipa::ToSchema;
+use utoipa::openapi::schema::Schema;
+use utoipa::openapi::{Components, OpenApi, RefOr};
+
+pub trait NamingStrategy {
+    fn schema_name(wrapper: &str, params: &[Cow<'static, str>]) -> String;
+
+    fn transparent_name(name: Cow<'static, str>) -> Cow<'static, str> {
+        name
+    }
+
+    fn wrapper_name(wrapper: &str, param: Cow<'static, str>) -> Cow<'static, str> {
+        Self::schema_name(wrapper, &[param]).into()
+    }
+}
+
+pub struct UnderscoreNaming;
+
+impl NamingStrategy for UnderscoreNaming {
+    fn schema_name(wrapper: &str, params: &[Cow<'static, str>]) -> String {
+        let mut name = wrapper.to_string();
+        for param in params {
+            name.push('_');
+            name.push_str(param);
+        }
+        name
+    }
+}
+
+struct Naming {
+    schema_name: fn(&str, &[Cow<'static, str>]) -> String,
+    wrapper_name: fn(&str, Cow<'static, str>) -> Cow<'static, str>,
+    transparent_name: fn(Cow<'static, str>) -> Cow<'static, str>,
+}
+
+static NAMING: OnceLock<Naming> = OnceLock::new();
+
+fn naming() -> &'static Naming {
+    NAMING.get_or_init(|| Naming {
+        schema_name: UnderscoreNaming::schema_name,
+        wrapper_name: UnderscoreNaming::wrapper_name,
+        transparent_name: UnderscoreNaming::transparent_name,
+    })
+}
+
+pub fn set_naming_strategy<N: NamingStrategy>() {
+    let strategy = Naming {
+        schema_name: N::schema_name,
+        wrapper_name: N::wrapper_name,
+        transparent_name: N::transparent_name,
+    };
+    assert!(
+        NAMING.set(strategy).is_ok(),
+        "the naming strategy was already set or a schema name was already built"
+    );
+}
+
+pub fn schema_name(wrapper: &str, params: &[Cow<'static, str>]) -> String {
+    (naming().schema_name)(wrapper, params)
+}
+
+pub fn wrapper_name<T: ToSchema>(wrapper: &str) -> Cow<'static, str> {
+    (naming().wrapper_name)(wrapper, T::name())
+}
+
+pub fn transparent_name<T: ToSchema>() -> Cow<'static, str> {
+    (naming().transparent_name)(T::name())
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DuplicateSchemaError {
+    pub names: Vec<String>,
+}
+
+impl fmt::Display for DuplicateSchemaError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "conflicting schemas registered as {}",
+            self.names.join(", ")
+        )
+    }
+}
+
+impl std::error::Error for DuplicateSchemaError {}
+
+#[derive(Default)]
+pub struct SchemaCollector {
+    schemas: BTreeMap<String, RefOr<Schema>>,
+    duplicates: Vec<String>,
+}
+
+impl SchemaCollector {
+    pub fn add<T: ToSchema>(&mut self) -> &mut Self {
+        let mut schemas = vec![(T::name().into_owned(), T::schema())];
+        T::schemas(&mut schemas);
+        self.extend(schemas)
+    }
+
+    pub fn insert(&mut self, name: impl Into<String>, schema: RefOr<Schema>) -> &mut Self {
+        let name = name.into();
+        match self.schemas.get(&name) {
+            Some(existing) if *existing != schema => {
+                if !self.duplicates.contains(&name) {
+                    self.duplicates.push(name);
+                }
+            }
+            Some(_) => {}
+            None => {
+                self.schemas.insert(name, schema);
+            }
+        }
+        self
+    }
+
+    pub fn extend(
+        &mut self,
+        schemas: impl IntoIterator<Item = (String, RefOr<Schema>)>,
+    ) -> &mut Self {
+        for (name, schema) in schemas {
+            self.insert(name, schema);
+        }
+        self
+    }
+
+    pub fn append(&mut self, other: Self) -> &mut Self {
+        for name in other.duplicates {
+            if !self.duplicates.contains(&name) {
+                self.duplicates.push(name);
+            }
+        }
+        self.extend(other.schemas)
+    }
+
+    pub fn add_openapi(&mut self, openapi: &OpenApi) -> &mut Self {
+        if let Some(components) = &openapi.components {
+            self.extend(components.schemas.clone());
+        }
+        self
+    }
+
+    pub fn finish(self) -> Result<BTreeMap<String, RefOr<Schema>>, DuplicateSchemaError> {
+        if self.duplicates.is_empty() {
+            Ok(self.schemas)
+        } else {
+            Err(DuplicateSchemaError {
+                names: self.duplicates,
+            })
+        }
+    }
+
+    pub fn apply(mut self, openapi: &mut OpenApi) -> Result<(), DuplicateSchemaError> {
+        self.add_openapi(openapi);
+        let schemas = self.finish()?;
+        openapi
+            .components
+            .get_or_insert_with(Components::new)
+            .schemas
+            .extend(schemas);
+        Ok(())
+    }
+}
+
+pub fn merge_openapi(openapi: &mut OpenApi, other: OpenApi) -> Result<(), DuplicateSchemaError> {
+    let mut collector = SchemaCollector::default();
+    collector.add_openapi(openapi).add_openapi(&other);
+    collector.finish()?;
+    openapi.merge(other);
+    Ok(())
+}
+
+#[cfg(feature = "router")]
+pub struct CheckedRouter<S = ()> {
+    router: utoipa_axum::router::OpenApiRouter<S>,
+    collector: SchemaCollector,
+}
+
+#[cfg(feature = "router")]
+impl<S> CheckedRouter<S>
+where
+    S: Send + Sync + Clone + 'static,
+{
+    pub fn with_openapi(openapi: OpenApi) -> Self {
+        let mut collector = SchemaCollector::default();
+        collector.add_openapi(&openapi);
+        Self {
+            router: utoipa_axum::router::OpenApiRouter::with_openapi(openapi),
+            collector,
+        }
+    }
+
+    #[must_use]
+    pub fn routes(mut self, routes: utoipa_axum::router::UtoipaMethodRouter<S>) -> Self {
+        self.collector.extend(routes.0.iter().cloned());
+        self.router = self.router.routes(routes);
+        self
+    }
+
+    #[must_use]
+    pub fn nest(mut self, path: &str, other: Self) -> Self {
+        self.collector.append(other.collector);
+        self.router = self.router.nest(path, other.router);
+        self
+    }
+
+    #[must_use]
+    pub fn merge(mut self, other: Self) -> Self {
+        self.collector.append(other.collector);
+        self.router = self.router.merge(other.router);
+        self
+    }
+
+    pub fn split_for_parts(self) -> Result<(axum::Router<S>, OpenApi), DuplicateSchemaError> {
+        let (router, mut openapi) = self.router.split_for_parts();
+        self.collector.apply(&mut openapi)?;
+        Ok((router, openapi))
+    }
+}
@@ -0,0 +1,188 @@
+use axum::extract::FromRequestParts;
+use axum::http::header::CONTENT_TYPE;
+use axum::http::header::HeaderValue;
+use axum::http::request::Parts;
+use axum::response::IntoResponse;
+use axum::response::sse::{Event, KeepAlive, Sse};
+use futures_util::{Stream, StreamExt};
+use serde::Serialize;
+use std::{borrow::Cow, convert::Infallible, time::Duration};
+use utoipa::IntoParams;
+use utoipa::PartialSchema;
+use utoipa::ToSchema;
+use utoipa::openapi::extensions::ExtensionsBuilder;
+use utoipa::openapi::path::{Parameter, ParameterBuilder, ParameterIn};
+use utoipa::openapi::schema::AllOfBuilder;
+
+use crate::content_type_trait::{ContentTypeEventStream, ContentTypeTrait};
+use crate::cookies::{Cookies, impl_with_cookie};
+use crate::schema_name::{transparent_name, wrapper_name};
+
+pub const LAST_EVENT_ID: &str = "last-event-id";
+
+pub trait SseEventTrait: Send {
+    const EVENT_NAMES: &'static [&'static str] = &[];
+
+    fn event_name(&self) -> Option<Cow<'static, str>> {
+        None
+    }
+
+    fn event_id(&self) -> Option<String> {
+        None
+    }
+}
+
+pub struct SseResponse<S> {
+    stream: S,
+    keep_alive: Option<KeepAlive>,
+    retry: Option<Duration>,
+    cookies: Cookies,
+}
+
+impl<S, E> SseResponse<S>
+where
+    S: Stream<Item = E> + Send + 'static,
+    E: ToSchema + Serialize + SseEventTrait + 'static,
+{
+    pub fn new(stream: S) -> Self {
+        Self {
+            stream,
+            keep_alive: None,
+            retry: None,
+            cookies: Cookies::default(),
+        }
+    }
+
+    #[must_use]
+    pub fn with_keep_alive(mut self, keep_alive: KeepAlive) -> Self {
+        self.keep_alive = Some(keep_alive);
+        self
+    }
+
+    #[must_use]
+    pub fn with_retry(mut self, retry: Duration) -> Self {
+        self.retry = Some(retry);
+        self
+    }
+
+    impl_with_cookie!();
+}
+
+fn sse_event<E: Serialize + SseEventTrait>(
+    data: &E,
+    retry: Option<Duration>,
+) -> Result<Event, axum::Error> {
+    let mut event = Event::default().json_data(data)?;
+    if let Some(name) = data.event_name() {
+        debug_assert!(
+            E::EVENT_NAMES.is_empty() || E::EVENT_NAMES.contains(&name.as_ref()),
+            "event `{name}` is missing from EVENT_NAMES"
+        );
+        event = event.event(name);
+    }
+    if let Some(id) = data.event_id() {
+        event = event.id(id);
+    }
+    if let Some(retry) = retry {
+        event = event.retry(retry);
+    }
+    Ok(event)
+}
+
+impl<S, E> IntoResponse for SseResponse<S>
+where
+    S: Stream<Item = E> + Send + 'static,
+    E: ToSchema + Serialize + SseEventTrait + 'static,
+{
+    fn into_response(self) -> axum::response::Response {
+        let mut retry = self.retry;
+        let events = self.stream.map(move |data| sse_event(&data, retry.take()));
+        let sse = Sse::new(events);
+        let mut res = match self.keep_alive {
+            Some(keep_alive) => sse.keep_alive(keep_alive).into_response(),
+            None => sse.into_response(),
+        };
+        res.headers_mut().insert(
+            CONTENT_TYPE,
+            HeaderValue::from_static(ContentTypeEventStream::content_type()),
+        );
+        self.cookies.apply(res.headers_mut());
+        res
+    }
+}
+
+impl<S, E> PartialSchema for SseResponse<S>
+where
+    S: Stream<Item = E> + Send + 'static,
+    E: ToSchema + Serialize + SseEventTrait + 'static,
+{
+    fn schema() -> utoipa::openapi::RefOr<utoipa::openapi::schema::Schema> {
+        if E::EVENT_NAMES.is_empty() {
+            return E::schema();
+        }
+        AllOfBuilder::new()
+            .item(E::schema())
+            .description(Some(format!("Events: {}", E::EVENT_NAMES.join(", "))))
+            .extensions(Some(
+                ExtensionsBuilder::new()
+                    .add("x-sse-events", E::EVENT_NAMES)
+                    .build(),
+            ))
+            .into()
+    }
+}
+
+impl<S, E> ToSchema for SseResponse<S>
+where
+    S: Stream<Item = E> + Send + 'static,
+    E: ToSchema + Serialize + SseEventTrait + 'static,
+{
+    fn name() -> Cow<'static, str> {
+        if E::EVENT_NAMES.is_empty() {
+            transparent_name::<E>()
+        } else {
+            wrapper_name::<E>("Sse")
+        }
+    }
+
+    fn schemas(
+        schemas: &mut Vec<(
+            String,
+            utoipa::openapi::RefOr<utoipa::openapi::schema::Schema>,
+        )>,
+    ) {
+        E::schemas(schemas);
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct LastEventId(pub Option<String>);
+
+impl<S> FromRequestParts<S> for LastEventId
+where
+    S: Send + Sync,
+{
+    type Rejection = Infallible;
+
+    async fn from_request_parts(parts: &mut Parts, _: &S) -> Result<Self, Self::Rejection> {
+        let id = parts
+            .headers
+            .get(LAST_EVENT_ID)
+            .and_then(|value| value.to_str().ok())
+            .map(ToString::to_string);
+        Ok(Self(id))
+    }
+}
+
+impl IntoParams for LastEventId {
+    fn into_params(_: impl Fn() -> Option<ParameterIn>) -> Vec<Parameter> {
+        vec![
+            ParameterBuilder::new()
+                .name("Last-Event-ID")
+                .parameter_in(ParameterIn::Header)
+                .description(Some("Id of the last event received before reconnecting"))
+                .schema(Some(String::schema()))
+                .build(),
+        ]
+    }
+}
@@ -1,7 +1,80 @@
 use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use std::borrow::Cow;
+use utoipa::{PartialSchema, ToSchema};
+
+use crate::schema_name::transparent_name;
 
 pub trait StatusCodeTrait: Send + Sync {
     fn status_code() -> StatusCode;
+
+    fn response_key() -> Cow<'static, str> {
+        Self::status_code().as_u16().to_string().into()
+    }
+
+    fn resolve(_status: Option<StatusCode>) -> StatusCode {
+        Self::status_code()
+    }
+
+    fn allows_body() -> bool {
+        status_allows_body(Self::status_code())
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct ExplicitStatus;
+
+pub fn explicit_status(res: &Response) -> Option<StatusCode> {
+    res.extensions()
+        .get::<ExplicitStatus>()
+        .map(|_| res.status())
+}
+
+pub struct WithStatus<T> {
+    inner: T,
+    status: StatusCode,
+}
+
+impl<T> WithStatus<T> {
+    pub fn new(inner: T, status: StatusCode) -> Self {
+        Self { inner, status }
+    }
+}
+
+impl<T: IntoResponse> IntoResponse for WithStatus<T> {
+    fn into_response(self) -> Response {
+        let mut res = self.inner.into_response();
+        *res.status_mut() = self.status;
+        res.extensions_mut().insert(ExplicitStatus);
+        res
+    }
+}
+
+impl<T: PartialSchema> PartialSchema for WithStatus<T> {
+    fn schema() -> utoipa::openapi::RefOr<utoipa::openapi::schema::Schema> {
+        T::schema()
+    }
+}
+
+impl<T: ToSchema> ToSchema for WithStatus<T> {
+    fn name() -> Cow<'static, str> {
+        transparent_name::<T>()
+    }
+
+    fn schemas(
+        schemas: &mut Vec<(
+            String,
+            utoipa::openapi::RefOr<utoipa::openapi::schema::Schema>,
+        )>,
+    ) {
+        T::schemas(schemas);
+    }
+}
+
+pub fn status_allows_body(status: StatusCode) -> bool {
+    !(status.is_informational()
+        || status == StatusCode::NO_CONTENT
+        || status == StatusCode::NOT_MODIFIED)
 }
 
 pub struct StatusCodeOk {}
@@ -35,3 +108,53 @@ impl<const S: u16> StatusCodeTrait for StatusCodeValue<S> {
         StatusCode::from_u16(S).unwrap_or(StatusCode::OK)
     }
 }
+
+pub struct StatusCodeRange<const R: u16> {}
+
+impl<const R: u16> StatusCodeTrait for StatusCodeRange<R> {
+    fn status_code() -> StatusCode {
+        StatusCode::INTERNAL_SERVER_ERROR
+    }
+
+    fn response_key() -> Cow<'static, str> {
+        format!("{R}XX").into()
+    }
+
+    fn allows_body() -> bool {
+        R != 1
+    }
+
+    fn resolve(status: Option<StatusCode>) -> StatusCode {
+        if let Some(status) = status {
+            debug_assert!(
+                status.as_u16() / 100 == R,
+                "status {status} is outside the documented {R}XX range"
+            );
+        }
+        status
+            .filter(|status| status.as_u16() / 100 == R)
+            .unwrap_or_else(Self::status_code)
+    }
+}
+
+pub type StatusCode1XX = StatusCodeRange<1>;
+pub type StatusCode2XX = StatusCodeRange<2>;
+pub type StatusCode3XX = StatusCodeRange<3>;
+pub type StatusCode4XX = StatusCodeRange<4>;
+pub type StatusCode5XX = StatusCodeRange<5>;
+
+pub struct StatusCodeDefault {}
+
+impl StatusCodeTrait for StatusCodeDefault {
+    fn status_code() -> StatusCode {
+        StatusCode::INTERNAL_SERVER_ERROR
+    }
+
+    fn response_key() -> Cow<'static, str> {
+        Cow::Borrowed("default")
+    }
+
+    fn resolve(status: Option<StatusCode>) -> StatusCode {
+        status.unwrap_or_else(Self::status_code)
+    }
+}
@@ -0,0 +1,119 @@
+use askama::Template;
+use axum::body::Body;
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+use std::{borrow::Cow, collections::BTreeMap, marker::PhantomData};
+use utoipa::openapi::extensions::ExtensionsBuilder;
+use utoipa::openapi::schema::{ObjectBuilder, Type};
+use utoipa::openapi::{RefOr, Response};
+use utoipa::{IntoResponses, PartialSchema, ToSchema};
+
+use crate::html_response::HtmlResponse;
+use crate::problem_details::{
+    ABOUT_BLANK, IntoProblemDetails, ProblemDetails, ProblemVariant, problem_responses,
+};
+use crate::schema_name::schema_name;
+
+pub struct Rendered<T> {
+    html: String,
+    template: PhantomData<fn() -> T>,
+}
+
+impl<T> Rendered<T>
+where
+    T: Template,
+{
+    pub fn new(template: &T) -> Result<Self, TemplateError> {
+        let html = template.render().map_err(TemplateError)?;
+        Ok(Self {
+            html,
+            template: PhantomData,
+        })
+    }
+
+    pub fn template_name() -> &'static str {
+        let full_type_name = std::any::type_name::<T>();
+        let type_name = full_type_name
+            .split_once('<')
+            .map_or(full_type_name, |(s, _)| s);
+        type_name.rsplit_once("::").map_or(type_name, |(_, s)| s)
+    }
+}
+
+impl<T> From<Rendered<T>> for Body {
+    fn from(item: Rendered<T>) -> Self {
+        item.html.into()
+    }
+}
+
+impl<T> PartialSchema for Rendered<T>
+where
+    T: Template,
+{
+    fn schema() -> RefOr<utoipa::openapi::schema::Schema> {
+        let extensions = ExtensionsBuilder::new()
+            .add("x-template", Self::template_name())
+            .build();
+        ObjectBuilder::new()
+            .schema_type(Type::String)
+            .extensions(Some(extensions))
+            .into()
+    }
+}
+
+impl<T> ToSchema for Rendered<T>
+where
+    T: Template,
+{
+    fn name() -> Cow<'static, str> {
+        schema_name("Rendered", &[Self::template_name().into()]).into()
+    }
+}
+
+impl<T> HtmlResponse<Rendered<T>>
+where
+    T: Template,
+{
+    pub fn from_template(template: &T) -> Result<Self, TemplateError> {
+        Rendered::new(template).map(Self::new)
+    }
+}
+
+#[derive(Debug)]
+pub struct TemplateError(pub askama::Error);
+
+impl std::fmt::Display for TemplateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "template render error: {}", self.0)
+    }
+}
+
+impl std::error::Error for TemplateError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.0)
+    }
+}
+
+impl IntoProblemDetails for TemplateError {
+    fn problem_details(&self) -> ProblemDetails {
+        ProblemDetails::new(StatusCode::INTERNAL_SERVER_ERROR).with_title("Template render error")
+    }
+}
+
+impl IntoResponse for TemplateError {
+    fn into_response(self) -> axum::response::Response {
+        tracing::error!(error = %self.0, "template render failed");
+        self.problem_details().into_response()
+    }
+}
+
+impl IntoResponses for TemplateError {
+    fn responses() -> BTreeMap<String, RefOr<Response>> {
+        problem_responses(&[ProblemVariant {
+            name: "TemplateError",
+            status: StatusCode::INTERNAL_SERVER_ERROR,
+            problem_type: ABOUT_BLANK,
+            title: Some("Template render error"),
+        }])
+    }
+}
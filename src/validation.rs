@@ -0,0 +1,803 @@
+use axum::extract::rejection::QueryRejection;
+use axum::extract::{FromRequest, FromRequestParts, Query, Request};
+use axum::http::StatusCode;
+use axum::http::request::Parts;
+use axum::response::IntoResponse;
+use regex::Regex;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::any::TypeId;
+use std::cell::RefCell;
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+use std::sync::{Arc, Mutex, OnceLock, PoisonError};
+use utoipa::Number;
+use utoipa::openapi::content::ContentBuilder;
+use utoipa::openapi::example::ExampleBuilder;
+use utoipa::openapi::schema::{
+    AdditionalProperties, AllOfBuilder, ArrayBuilder, ArrayItems, Object, ObjectBuilder, Schema,
+    SchemaType, Type,
+};
+use utoipa::openapi::{RefOr, Response, ResponseBuilder};
+use utoipa::{IntoResponses, PartialSchema, ToSchema};
+
+use crate::content_type_trait::{ContentTypeProblemJson, ContentTypeTrait};
+use crate::json_body::{JsonBody, JsonBodyRejection, json_bytes};
+use crate::problem_details::{
+    ABOUT_BLANK, IntoProblemDetails, ProblemDetails, ProblemVariant, problem_responses,
+};
+
+/// One violated schema constraint, located by the path of the field.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct FieldError {
+    /// Path of the offending field, `.` for the value itself
+    pub path: String,
+    pub message: String,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ValidationErrors {
+    pub errors: Vec<FieldError>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum ValidationError {
+    Invalid(ValidationErrors),
+    Schema(String),
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Invalid(e) => e.fmt(f),
+            Self::Schema(e) => write!(f, "invalid schema: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for ValidationError {}
+
+pub fn validate<T: ToSchema + 'static>(value: &Value) -> Result<(), ValidationError> {
+    Validator::of::<T>().run(value, false)
+}
+
+fn validate_query<T: ToSchema + 'static>(value: &Value) -> Result<(), ValidationError> {
+    Validator::of::<T>().run(value, true)
+}
+
+fn query_value(pairs: Vec<(String, String)>) -> Value {
+    let mut map = Map::new();
+    for (name, value) in pairs {
+        match map.get_mut(&name) {
+            Some(Value::Array(values)) => values.push(value.into()),
+            Some(existing) => *existing = Value::Array(vec![existing.take(), value.into()]),
+            None => {
+                map.insert(name, value.into());
+            }
+        }
+    }
+    Value::Object(map)
+}
+
+fn compiled(pattern: &str) -> Result<Regex, String> {
+    static PATTERNS: OnceLock<Mutex<HashMap<String, Result<Regex, String>>>> = OnceLock::new();
+    PATTERNS
+        .get_or_init(Mutex::default)
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .entry(pattern.to_string())
+        .or_insert_with(|| Regex::new(pattern).map_err(|e| e.to_string()))
+        .clone()
+}
+
+struct Validator {
+    schema: RefOr<Schema>,
+    components: BTreeMap<String, RefOr<Schema>>,
+}
+
+struct Check<'a> {
+    components: &'a BTreeMap<String, RefOr<Schema>>,
+    coerce: bool,
+    schema_error: RefCell<Option<String>>,
+}
+
+fn field_path(path: &str, key: &str) -> String {
+    if path.is_empty() {
+        key.to_string()
+    } else {
+        format!("{path}.{key}")
+    }
+}
+
+fn push(errors: &mut Vec<FieldError>, path: &str, message: String) {
+    let path = if path.is_empty() { "." } else { path };
+    errors.push(FieldError {
+        path: path.to_string(),
+        message,
+    });
+}
+
+fn label(value: &impl Serialize) -> String {
+    match serde_json::to_value(value) {
+        Ok(Value::String(s)) => s,
+        Ok(Value::Array(items)) => {
+            let items: Vec<_> = items.iter().filter_map(Value::as_str).collect();
+            items.join(" or ")
+        }
+        _ => String::new(),
+    }
+}
+
+fn number(n: &Number) -> f64 {
+    match n {
+        Number::Int(i) => *i as f64,
+        Number::UInt(u) => *u as f64,
+        Number::Float(f) => *f,
+    }
+}
+
+fn type_matches(schema_type: &SchemaType, value: &Value) -> bool {
+    let matches = |t: &Type| match t {
+        Type::Object => value.is_object(),
+        Type::String => value.is_string(),
+        Type::Integer => {
+            value.is_i64() || value.is_u64() || value.as_f64().is_some_and(|f| f.fract() == 0.0)
+        }
+        Type::Number => value.is_number(),
+        Type::Boolean => value.is_boolean(),
+        Type::Array => value.is_array(),
+        Type::Null => value.is_null(),
+    };
+    match schema_type {
+        SchemaType::Type(t) => matches(t),
+        SchemaType::Array(types) => types.iter().any(matches),
+        SchemaType::AnyValue => true,
+    }
+}
+
+fn integer_range(format: &str) -> Option<(i128, i128)> {
+    match format {
+        "int8" => Some((i8::MIN.into(), i8::MAX.into())),
+        "int16" => Some((i16::MIN.into(), i16::MAX.into())),
+        "int32" => Some((i32::MIN.into(), i32::MAX.into())),
+        "int64" => Some((i64::MIN.into(), i64::MAX.into())),
+        "uint8" => Some((0, u8::MAX.into())),
+        "uint16" => Some((0, u16::MAX.into())),
+        "uint32" => Some((0, u32::MAX.into())),
+        "uint64" => Some((0, u64::MAX.into())),
+        _ => None,
+    }
+}
+
+fn digits(s: &str, len: usize) -> Option<u32> {
+    (s.len() == len && s.bytes().all(|b| b.is_ascii_digit()))
+        .then(|| s.parse().ok())
+        .flatten()
+}
+
+fn is_date(s: &str) -> bool {
+    let mut parts = s.splitn(3, '-');
+    let (Some(year), Some(month), Some(day)) = (
+        parts.next().and_then(|p| digits(p, 4)),
+        parts.next().and_then(|p| digits(p, 2)),
+        parts.next().and_then(|p| digits(p, 2)),
+    ) else {
+        return false;
+    };
+    let leap = year % 4 == 0 && (year % 100 != 0 || year % 400 == 0);
+    let days = match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        2 if leap => 29,
+        2 => 28,
+        _ => return false,
+    };
+    (1..=days).contains(&day)
+}
+
+fn is_time(s: &str, offset_required: bool) -> bool {
+    let (time, offset) = match s.find(['Z', 'z', '+', '-']) {
+        Some(idx) => s.split_at(idx),
+        None if offset_required => return false,
+        None => (s, ""),
+    };
+    let offset_ok = match offset.as_bytes().first() {
+        None => true,
+        Some(b'Z' | b'z') => offset.len() == 1,
+        Some(_) => is_time(&format!("{}:00", &offset[1..]), false),
+    };
+    let time = match time.split_once('.') {
+        Some((time, fraction))
+            if !fraction.is_empty() && fraction.bytes().all(|b| b.is_ascii_digit()) =>
+        {
+            time
+        }
+        Some(_) => return false,
+        None => time,
+    };
+    let mut parts = time.splitn(3, ':');
+    let (Some(hour), Some(minute), Some(second)) = (
+        parts.next().and_then(|p| digits(p, 2)),
+        parts.next().and_then(|p| digits(p, 2)),
+        parts.next().and_then(|p| digits(p, 2)),
+    ) else {
+        return false;
+    };
+    offset_ok && hour < 24 && minute < 60 && second <= 60
+}
+
+fn is_email(s: &str) -> bool {
+    s.rsplit_once('@').is_some_and(|(local, domain)| {
+        !local.is_empty()
+            && !domain.is_empty()
+            && !domain.starts_with('.')
+            && !domain.ends_with('.')
+            && !s.contains(char::is_whitespace)
+    })
+}
+
+fn is_uuid(s: &str) -> bool {
+    let groups: Vec<_> = s.split('-').collect();
+    groups.len() == 5
+        && groups
+            .iter()
+            .zip([8, 4, 4, 4, 12])
+            .all(|(group, len)| group.len() == len && group.chars().all(|c| c.is_ascii_hexdigit()))
+}
+
+fn string_format_ok(format: &str, s: &str) -> bool {
+    match format {
+        "date" => is_date(s),
+        "date-time" => s
+            .split_once(['T', 't', ' '])
+            .is_some_and(|(date, time)| is_date(date) && is_time(time, true)),
+        "time" => is_time(s, false),
+        "email" | "idn-email" => is_email(s),
+        "uuid" => is_uuid(s),
+        "ipv4" => s.parse::<std::net::Ipv4Addr>().is_ok(),
+        "ipv6" => s.parse::<std::net::Ipv6Addr>().is_ok(),
+        _ => true,
+    }
+}
+
+impl Validator {
+    fn of<T: ToSchema + 'static>() -> Arc<Self> {
+        static VALIDATORS: OnceLock<Mutex<HashMap<TypeId, Arc<Validator>>>> = OnceLock::new();
+        VALIDATORS
+            .get_or_init(Mutex::default)
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .entry(TypeId::of::<T>())
+            .or_insert_with(|| {
+                let mut components = Vec::new();
+                T::schemas(&mut components);
+                Arc::new(Self {
+                    schema: T::schema(),
+                    components: components.into_iter().collect(),
+                })
+            })
+            .clone()
+    }
+
+    fn run(&self, value: &Value, coerce: bool) -> Result<(), ValidationError> {
+        Check {
+            components: &self.components,
+            coerce,
+            schema_error: RefCell::default(),
+        }
+        .run(&self.schema, value)
+    }
+}
+
+impl Check<'_> {
+    fn run(&self, schema: &RefOr<Schema>, value: &Value) -> Result<(), ValidationError> {
+        let mut errors = Vec::new();
+        self.check(schema, value, "", &mut errors);
+        if let Some(e) = self.schema_error.take() {
+            Err(ValidationError::Schema(e))
+        } else if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(ValidationError::Invalid(ValidationErrors { errors }))
+        }
+    }
+
+    fn resolve<'a>(&'a self, schema: &'a RefOr<Schema>) -> Option<&'a Schema> {
+        match schema {
+            RefOr::T(schema) => Some(schema),
+            RefOr::Ref(r) => {
+                let name = r.ref_location.rsplit('/').next()?;
+                self.resolve(self.components.get(name)?)
+            }
+        }
+    }
+
+    fn check(
+        &self,
+        schema: &RefOr<Schema>,
+        value: &Value,
+        path: &str,
+        errors: &mut Vec<FieldError>,
+    ) {
+        if let Some(schema) = self.resolve(schema) {
+            self.check_schema(schema, value, path, errors);
+        }
+    }
+
+    fn check_schema(
+        &self,
+        schema: &Schema,
+        value: &Value,
+        path: &str,
+        errors: &mut Vec<FieldError>,
+    ) {
+        match schema {
+            Schema::Object(object) => self.check_object(object, value, path, errors),
+            Schema::Array(_) if self.coerce && !value.is_array() => {
+                let single = Value::Array(vec![value.clone()]);
+                self.check_schema(schema, &single, path, errors);
+            }
+            Schema::Array(array) => {
+                if !type_matches(&array.schema_type, value) {
+                    push(
+                        errors,
+                        path,
+                        format!("expected {}", label(&array.schema_type)),
+                    );
+                    return;
+                }
+                let Some(items) = value.as_array() else {
+                    return;
+                };
+                if let Some(min) = array.min_items.filter(|min| items.len() < *min) {
+                    push(errors, path, format!("must have at least {min} items"));
+                }
+                if let Some(max) = array.max_items.filter(|max| items.len() > *max) {
+                    push(errors, path, format!("must have at most {max} items"));
+                }
+                if array.unique_items
+                    && items
+                        .iter()
+                        .enumerate()
+                        .any(|(i, item)| items[..i].contains(item))
+                {
+                    push(errors, path, "items must be unique".into());
+                }
+                if let ArrayItems::RefOrSchema(item_schema) = &array.items {
+                    for (i, item) in items.iter().enumerate() {
+                        self.check(item_schema, item, &format!("{path}[{i}]"), errors);
+                    }
+                }
+            }
+            Schema::AllOf(all_of) => {
+                for item in &all_of.items {
+                    self.check(item, value, path, errors);
+                }
+            }
+            Schema::OneOf(one_of) => self.check_any(&one_of.items, true, value, path, errors),
+            Schema::AnyOf(any_of) => self.check_any(&any_of.items, false, value, path, errors),
+            _ => {}
+        }
+    }
+
+    fn check_any(
+        &self,
+        alternatives: &[RefOr<Schema>],
+        exclusive: bool,
+        value: &Value,
+        path: &str,
+        errors: &mut Vec<FieldError>,
+    ) {
+        if alternatives.is_empty() {
+            return;
+        }
+        let mut matched = 0;
+        let mut candidates = Vec::new();
+        for alternative in alternatives {
+            let mut alternative_errors = Vec::new();
+            self.check(alternative, value, path, &mut alternative_errors);
+            if alternative_errors.is_empty() {
+                if !exclusive {
+                    return;
+                }
+                matched += 1;
+                continue;
+            }
+            let typed = match self.resolve(alternative) {
+                Some(Schema::Object(object)) => type_matches(&object.schema_type, value),
+                Some(Schema::Array(array)) => type_matches(&array.schema_type, value),
+                _ => true,
+            };
+            if typed {
+                candidates.push(alternative_errors);
+            }
+        }
+        match matched {
+            0 if candidates.len() == 1 => errors.append(&mut candidates[0]),
+            0 => push(errors, path, "does not match any allowed schema".into()),
+            1 => {}
+            _ => push(errors, path, "matches more than one allowed schema".into()),
+        }
+    }
+
+    fn check_object(
+        &self,
+        object: &Object,
+        value: &Value,
+        path: &str,
+        errors: &mut Vec<FieldError>,
+    ) {
+        if !type_matches(&object.schema_type, value) {
+            if self.coerce
+                && let Value::String(s) = value
+                && let Ok(parsed) = serde_json::from_str::<Value>(s)
+                && (parsed.is_number() || parsed.is_boolean())
+                && type_matches(&object.schema_type, &parsed)
+            {
+                return self.check_object(object, &parsed, path, errors);
+            }
+            push(
+                errors,
+                path,
+                format!("expected {}", label(&object.schema_type)),
+            );
+            return;
+        }
+        if let Some(values) = &object.enum_values
+            && !values.contains(value)
+        {
+            let values: Vec<_> = values.iter().map(Value::to_string).collect();
+            push(
+                errors,
+                path,
+                format!("must be one of {}", values.join(", ")),
+            );
+        }
+        let format = object.format.as_ref().map(label);
+        match value {
+            Value::Number(n) => {
+                let Some(v) = n.as_f64() else {
+                    return;
+                };
+                self.check_number(object, v, path, errors);
+                let integer = n.as_i64().map(i128::from).or(n.as_u64().map(i128::from));
+                if let (Some((min, max)), Some(i)) =
+                    (format.as_deref().and_then(integer_range), integer)
+                    && !(min..=max).contains(&i)
+                {
+                    push(
+                        errors,
+                        path,
+                        format!("must be a valid {}", format.unwrap_or_default()),
+                    );
+                }
+            }
+            Value::String(s) => {
+                let length = s.chars().count();
+                if let Some(min) = object.min_length.filter(|min| length < *min) {
+                    push(errors, path, format!("must be at least {min} characters"));
+                }
+                if let Some(max) = object.max_length.filter(|max| length > *max) {
+                    push(errors, path, format!("must be at most {max} characters"));
+                }
+                if let Some(pattern) = &object.pattern {
+                    match compiled(pattern) {
+                        Ok(re) if !re.is_match(s) => {
+                            push(errors, path, format!("must match pattern `{pattern}`"));
+                        }
+                        Ok(_) => {}
+                        Err(e) => {
+                            self.schema_error
+                                .borrow_mut()
+                                .get_or_insert_with(|| format!("pattern `{pattern}`: {e}"));
+                        }
+                    }
+                }
+                if let Some(format) = format
+                    && !string_format_ok(&format, s)
+                {
+                    push(errors, path, format!("must be a valid {format}"));
+                }
+            }
+            Value::Object(map) => self.check_properties(object, map, path, errors),
+            _ => {}
+        }
+    }
+
+    fn check_number(&self, object: &Object, v: f64, path: &str, errors: &mut Vec<FieldError>) {
+        if let Some(min) = object.minimum.as_ref().map(number)
+            && v < min
+        {
+            push(errors, path, format!("must be at least {min}"));
+        }
+        if let Some(min) = object.exclusive_minimum.as_ref().map(number)
+            && v <= min
+        {
+            push(errors, path, format!("must be greater than {min}"));
+        }
+        if let Some(max) = object.maximum.as_ref().map(number)
+            && v > max
+        {
+            push(errors, path, format!("must be at most {max}"));
+        }
+        if let Some(max) = object.exclusive_maximum.as_ref().map(number)
+            && v >= max
+        {
+            push(errors, path, format!("must be less than {max}"));
+        }
+        if let Some(step) = object.multiple_of.as_ref().map(number)
+            && step != 0.0
+            && ((v / step) - (v / step).round()).abs() > 1e-9 * (v / step).abs().max(1.0)
+        {
+            push(errors, path, format!("must be a multiple of {step}"));
+        }
+    }
+
+    fn check_properties(
+        &self,
+        object: &Object,
+        map: &Map<String, Value>,
+        path: &str,
+        errors: &mut Vec<FieldError>,
+    ) {
+        for required in &object.required {
+            if !map.contains_key(required) {
+                push(errors, &field_path(path, required), "is required".into());
+            }
+        }
+        if let Some(min) = object.min_properties.filter(|min| map.len() < *min) {
+            push(errors, path, format!("must have at least {min} properties"));
+        }
+        if let Some(max) = object.max_properties.filter(|max| map.len() > *max) {
+            push(errors, path, format!("must have at most {max} properties"));
+        }
+        for (key, value) in map {
+            let key_path = field_path(path, key);
+            if let Some(property) = object.properties.get(key) {
+                self.check(property, value, &key_path, errors);
+            } else {
+                match object.additional_properties.as_deref() {
+                    Some(AdditionalProperties::RefOr(schema)) => {
+                        self.check(schema, value, &key_path, errors);
+                    }
+                    Some(AdditionalProperties::FreeForm(false)) => {
+                        push(errors, &key_path, "is not allowed".into());
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+}
+
+impl fmt::Display for ValidationErrors {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let errors: Vec<_> = self
+            .errors
+            .iter()
+            .map(|e| format!("{}: {}", e.path, e.message))
+            .collect();
+        f.write_str(&errors.join("; "))
+    }
+}
+
+impl std::error::Error for ValidationErrors {}
+
+impl IntoProblemDetails for ValidationErrors {
+    fn problem_details(&self) -> ProblemDetails {
+        ProblemDetails::new(StatusCode::UNPROCESSABLE_ENTITY)
+            .with_title("Validation failed")
+            .with_detail(self.to_string())
+            .with_extension(
+                "errors",
+                serde_json::to_value(&self.errors).unwrap_or_default(),
+            )
+    }
+}
+
+impl IntoResponse for ValidationErrors {
+    fn into_response(self) -> axum::response::Response {
+        self.problem_details().into_response()
+    }
+}
+
+impl IntoResponses for ValidationErrors {
+    fn responses() -> BTreeMap<String, RefOr<Response>> {
+        let schema = AllOfBuilder::new().item(ProblemDetails::schema()).item(
+            ObjectBuilder::new()
+                .property("errors", ArrayBuilder::new().items(FieldError::schema()))
+                .required("errors"),
+        );
+        let example = ValidationErrors {
+            errors: vec![FieldError {
+                path: "name".into(),
+                message: "must be at least 1 characters".into(),
+            }],
+        }
+        .problem_details();
+        let example = ExampleBuilder::new()
+            .value(serde_json::to_value(&example).ok())
+            .build();
+        let content = ContentBuilder::new()
+            .schema(Some(schema))
+            .examples_from_iter([("Validation", example)])
+            .build();
+        let response = ResponseBuilder::new()
+            .description("Validation failed")
+            .content(ContentTypeProblemJson::content_type(), content)
+            .build();
+        BTreeMap::from([(
+            StatusCode::UNPROCESSABLE_ENTITY.as_str().to_string(),
+            response.into(),
+        )])
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct InvalidQuery(pub String);
+
+impl From<QueryRejection> for InvalidQuery {
+    fn from(rejection: QueryRejection) -> Self {
+        Self(rejection.body_text())
+    }
+}
+
+impl fmt::Display for InvalidQuery {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl std::error::Error for InvalidQuery {}
+
+impl IntoProblemDetails for InvalidQuery {
+    fn problem_details(&self) -> ProblemDetails {
+        ProblemDetails::new(StatusCode::BAD_REQUEST).with_detail(self.to_string())
+    }
+}
+
+impl IntoResponse for InvalidQuery {
+    fn into_response(self) -> axum::response::Response {
+        self.problem_details().into_response()
+    }
+}
+
+impl IntoResponses for InvalidQuery {
+    fn responses() -> BTreeMap<String, RefOr<Response>> {
+        problem_responses(&[ProblemVariant {
+            name: "InvalidQuery",
+            status: StatusCode::BAD_REQUEST,
+            problem_type: ABOUT_BLANK,
+            title: Some("Invalid query string"),
+        }])
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum ValidatedRejection<R> {
+    Extract(R),
+    Invalid(ValidationErrors),
+    Schema(String),
+}
+
+impl<R> From<ValidationErrors> for ValidatedRejection<R> {
+    fn from(errors: ValidationErrors) -> Self {
+        Self::Invalid(errors)
+    }
+}
+
+impl<R> From<ValidationError> for ValidatedRejection<R> {
+    fn from(error: ValidationError) -> Self {
+        match error {
+            ValidationError::Invalid(errors) => Self::Invalid(errors),
+            ValidationError::Schema(e) => Self::Schema(e),
+        }
+    }
+}
+
+impl<R> fmt::Display for ValidatedRejection<R>
+where
+    R: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Extract(e) => e.fmt(f),
+            Self::Invalid(e) => e.fmt(f),
+            Self::Schema(e) => write!(f, "invalid schema: {e}"),
+        }
+    }
+}
+
+impl<R> std::error::Error for ValidatedRejection<R> where R: fmt::Debug + fmt::Display {}
+
+impl<R> IntoResponse for ValidatedRejection<R>
+where
+    R: IntoResponse,
+{
+    fn into_response(self) -> axum::response::Response {
+        match self {
+            Self::Extract(e) => e.into_response(),
+            Self::Invalid(e) => e.into_response(),
+            Self::Schema(e) => {
+                tracing::error!(error = %e, "invalid validation schema");
+                ProblemDetails::new(StatusCode::INTERNAL_SERVER_ERROR).into_response()
+            }
+        }
+    }
+}
+
+impl<R> IntoResponses for ValidatedRejection<R>
+where
+    R: IntoResponses,
+{
+    fn responses() -> BTreeMap<String, RefOr<Response>> {
+        let mut responses = R::responses();
+        responses.extend(ValidationErrors::responses());
+        for (status, response) in problem_responses(&[ProblemVariant {
+            name: "Schema",
+            status: StatusCode::INTERNAL_SERVER_ERROR,
+            problem_type: ABOUT_BLANK,
+            title: None,
+        }]) {
+            responses.entry(status).or_insert(response);
+        }
+        responses
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Validated<E>(pub E);
+
+impl<T, S> FromRequest<S> for Validated<JsonBody<T>>
+where
+    T: DeserializeOwned + ToSchema + 'static,
+    S: Send + Sync,
+{
+    type Rejection = ValidatedRejection<JsonBodyRejection>;
+
+    async fn from_request(req: Request, state: &S) -> Result<Self, Self::Rejection> {
+        let bytes = json_bytes(req, state)
+            .await
+            .map_err(ValidatedRejection::Extract)?;
+        let JsonBody(value) =
+            JsonBody::<Value>::from_bytes(&bytes).map_err(ValidatedRejection::Extract)?;
+        validate::<T>(&value)?;
+        JsonBody::from_value(value).map(Self).map_err(|e| match e {
+            JsonBodyRejection::Data { path, message } => {
+                let path = if path.is_empty() { ".".into() } else { path };
+                ValidationErrors {
+                    errors: vec![FieldError { path, message }],
+                }
+                .into()
+            }
+            e => ValidatedRejection::Extract(e),
+        })
+    }
+}
+
+impl<T, S> FromRequestParts<S> for Validated<Query<T>>
+where
+    T: DeserializeOwned + ToSchema + 'static,
+    S: Send + Sync,
+{
+    type Rejection = ValidatedRejection<InvalidQuery>;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        let Query(pairs) = Query::<Vec<(String, String)>>::try_from_uri(&parts.uri)
+            .map_err(|e| ValidatedRejection::Extract(e.into()))?;
+        validate_query::<T>(&query_value(pairs))?;
+        Query::<T>::from_request_parts(parts, state)
+            .await
+            .map(Self)
+            .map_err(|e| {
+                ValidationErrors {
+                    errors: vec![FieldError {
+                        path: ".".into(),
+                        message: e.body_text(),
+                    }],
+                }
+                .into()
+            })
+    }
+}
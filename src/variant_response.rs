@@ -0,0 +1,16 @@
+use axum::Json;
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use serde::Serialize;
+
+use crate::problem_details::ProblemDetails;
+
+#[doc(hidden)]
+pub use serde;
+
+pub fn json_response<T: Serialize>(status: StatusCode, body: &T) -> Response {
+    match serde_json::to_value(body) {
+        Ok(value) => (status, Json(value)).into_response(),
+        Err(_) => ProblemDetails::new(StatusCode::INTERNAL_SERVER_ERROR).into_response(),
+    }
+}
@@ -0,0 +1,27 @@
+#![allow(dead_code)]
+
+use axum::Router;
+use axum::body::{Body, Bytes};
+use axum::http::{Request, StatusCode};
+use axum::response::Response;
+use serde_json::Value;
+use tower::ServiceExt;
+
+pub async fn body_bytes(res: Response) -> Bytes {
+    axum::body::to_bytes(res.into_body(), usize::MAX)
+        .await
+        .unwrap()
+}
+
+pub async fn body_json(res: Response) -> Value {
+    serde_json::from_slice(&body_bytes(res).await).unwrap()
+}
+
+pub async fn send(router: Router, req: Request<Body>) -> (StatusCode, Value) {
+    let res = router.oneshot(req).await.unwrap();
+    let status = res.status();
+    let body = body_bytes(res).await;
+    let body = serde_json::from_slice(&body)
+        .unwrap_or_else(|_| String::from_utf8_lossy(&body).into_owned().into());
+    (status, body)
+}
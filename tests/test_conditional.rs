@@ -0,0 +1,247 @@
+mod common;
+
+use axum::Router;
+use axum::body::Body;
+use axum::http::{HeaderMap, Method, Request, StatusCode};
+use axum::response::IntoResponse;
+use axum::routing::{get, put};
+use serde::Serialize;
+use std::time::{Duration, UNIX_EPOCH};
+use tower::ServiceExt;
+use utoipa::{IntoParams, IntoResponses, PartialSchema, ToSchema};
+
+use utoipa_helper::{
+    UtoipaResponse,
+    cache_policy::{CachePolicy, http_date},
+    etag::{ETag, EntityTags, IfMatch, IfNoneMatch, PreconditionError},
+    html_response::HtmlResponse,
+    json_response::JsonResponse,
+};
+
+#[derive(Serialize, ToSchema)]
+struct Item {
+    id: u32,
+}
+
+#[derive(UtoipaResponse)]
+#[response(status = OK, content = "application/json", description = "Item", etag)]
+#[rustfmt::skip]
+struct ItemResponse(JsonResponse::<Item>);
+
+#[derive(UtoipaResponse)]
+#[response(
+    status = OK,
+    content = "text/html",
+    description = "Cached page",
+    cache = CachePolicy::public().with_max_age(Duration::from_secs(300)).with_vary("Accept-Language"),
+)]
+#[rustfmt::skip]
+struct CachedPage(HtmlResponse::<String>);
+
+async fn get_item(if_none_match: IfNoneMatch) -> ItemResponse {
+    JsonResponse::new(Item { id: 1 })
+        .with_strong_etag()
+        .with_if_none_match(if_none_match)
+        .into()
+}
+
+#[test]
+fn test_etag_parse() {
+    let etag = ETag::strong_from_bytes(b"body");
+    assert_eq!(ETag::parse(&etag.to_string()), Some(etag.clone()));
+    assert_eq!(ETag::parse("W/\"abc\""), Some(ETag::Weak("abc".into())));
+    assert!(ETag::Weak("abc".into()).weak_eq(&ETag::Strong("abc".into())));
+    assert!(!ETag::Weak("abc".into()).strong_eq(&ETag::Strong("abc".into())));
+    assert_eq!(ETag::parse("abc"), None);
+
+    let mut headers = HeaderMap::new();
+    headers.insert(
+        "if-none-match",
+        "\"a,b\", W/\"c\", junk, \"d\"".parse().unwrap(),
+    );
+    let if_none_match = IfNoneMatch::from_request(&Method::GET, &headers);
+    assert_eq!(
+        if_none_match.tags,
+        EntityTags::Tags(vec![
+            ETag::Strong("a,b".into()),
+            ETag::Weak("c".into()),
+            ETag::Strong("d".into()),
+        ])
+    );
+}
+
+#[tokio::test]
+async fn test_if_none_match() {
+    let router = Router::new().route("/item", get(get_item));
+
+    let res = router
+        .clone()
+        .oneshot(Request::get("/item").body(Body::empty()).unwrap())
+        .await
+        .unwrap();
+    assert_eq!(res.status(), StatusCode::OK);
+    let etag = res.headers()["etag"].clone();
+
+    let res = router
+        .clone()
+        .oneshot(
+            Request::get("/item")
+                .header("if-none-match", etag.clone())
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(res.status(), StatusCode::NOT_MODIFIED);
+    assert_eq!(res.headers()["etag"], etag);
+    assert!(res.headers().get("content-type").is_none());
+    let body = common::body_bytes(res).await;
+    assert!(body.is_empty());
+
+    let res = router
+        .oneshot(
+            Request::get("/item")
+                .header("if-none-match", "\"stale\"")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(res.status(), StatusCode::OK);
+}
+
+#[test]
+fn test_if_none_match_methods() {
+    let etag = ETag::Strong("v1".into());
+    let check = |method: Method, status: StatusCode| {
+        IfNoneMatch::new(method, EntityTags::Tags(vec![etag.clone()]))
+            .precondition_response(&etag, status)
+            .map(|res| res.status())
+    };
+    assert_eq!(
+        check(Method::HEAD, StatusCode::OK),
+        Some(StatusCode::NOT_MODIFIED)
+    );
+    assert_eq!(
+        check(Method::PUT, StatusCode::OK),
+        Some(StatusCode::PRECONDITION_FAILED)
+    );
+    assert_eq!(check(Method::GET, StatusCode::NOT_FOUND), None);
+
+    let res = JsonResponse::new(Item { id: 1 })
+        .with_etag(etag.clone())
+        .with_if_none_match(IfNoneMatch::new(Method::POST, EntityTags::Any));
+    let res = ItemResponse::from(res).into_response();
+    assert_eq!(res.status(), StatusCode::PRECONDITION_FAILED);
+    assert_eq!(res.headers()["content-type"], "application/problem+json");
+}
+
+#[test]
+fn test_etag_documented() {
+    let responses = serde_json::to_value(ItemResponse::responses()).unwrap();
+    assert!(responses["200"]["headers"]["ETag"].is_object());
+    assert_eq!(responses["304"]["description"], "Not modified");
+    assert!(responses["304"].get("content").is_none());
+}
+
+#[test]
+fn test_html_etag() {
+    let res = HtmlResponse::new("<p>hi</p>".to_string())
+        .with_weak_etag()
+        .with_if_none_match(IfNoneMatch::new(Method::GET, EntityTags::Any))
+        .into_response();
+    assert_eq!(res.status(), StatusCode::NOT_MODIFIED);
+    assert!(res.headers()["etag"].to_str().unwrap().starts_with("W/\""));
+}
+
+async fn put_item(if_match: IfMatch) -> Result<StatusCode, PreconditionError> {
+    if_match.check(&ETag::Strong("v2".into()))?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[tokio::test]
+async fn test_if_match() {
+    let router = Router::new().route("/item", put(put_item));
+    let send = |if_match: Option<&'static str>| {
+        let mut request = Request::put("/item");
+        if let Some(if_match) = if_match {
+            request = request.header("if-match", if_match);
+        }
+        router.clone().oneshot(request.body(Body::empty()).unwrap())
+    };
+
+    let res = send(Some("\"v2\"")).await.unwrap();
+    assert_eq!(res.status(), StatusCode::NO_CONTENT);
+    let res = send(Some("*")).await.unwrap();
+    assert_eq!(res.status(), StatusCode::NO_CONTENT);
+    let res = send(Some("W/\"v2\"")).await.unwrap();
+    assert_eq!(res.status(), StatusCode::PRECONDITION_FAILED);
+    let res = send(Some("\"v1\"")).await.unwrap();
+    assert_eq!(res.status(), StatusCode::PRECONDITION_FAILED);
+    assert_eq!(res.headers()["content-type"], "application/problem+json");
+    let res = send(None).await.unwrap();
+    assert_eq!(res.status(), StatusCode::PRECONDITION_REQUIRED);
+}
+
+#[test]
+fn test_if_match_documented() {
+    let responses = serde_json::to_value(PreconditionError::responses()).unwrap();
+    assert_eq!(responses["412"]["description"], "Precondition Failed");
+    assert_eq!(responses["428"]["description"], "Precondition Required");
+
+    let params = serde_json::to_value(IfMatch::into_params(|| None)).unwrap();
+    assert_eq!(params[0]["name"], "If-Match");
+    assert_eq!(params[0]["in"], "header");
+    assert_eq!(params[0]["required"], true);
+}
+
+#[test]
+fn test_cache_policy() {
+    let res = CachedPage::from(HtmlResponse::new("<p>hi</p>".to_string())).into_response();
+    assert_eq!(res.headers()["cache-control"], "public, max-age=300");
+    assert_eq!(res.headers()["vary"], "Accept-Language");
+
+    let res = CachedPage::from(
+        HtmlResponse::new("<p>hi</p>".to_string()).with_cache_policy(CachePolicy::no_store()),
+    )
+    .into_response();
+    assert_eq!(res.headers()["cache-control"], "no-store");
+
+    let responses = serde_json::to_value(CachedPage::responses()).unwrap();
+    assert_eq!(
+        responses["200"]["headers"]["Cache-Control"]["description"],
+        "public, max-age=300"
+    );
+    assert!(responses["200"]["headers"]["Vary"].is_object());
+
+    let policy = CachePolicy::private()
+        .with_max_age(Duration::from_secs(60))
+        .immutable()
+        .with_s_maxage(Duration::from_secs(10))
+        .with_expires(UNIX_EPOCH + Duration::from_secs(784_111_777))
+        .with_surrogate_max_age(Duration::from_secs(3600));
+    let res = JsonResponse::new(Item { id: 1 })
+        .with_cache_policy(policy)
+        .into_response();
+    assert_eq!(
+        res.headers()["cache-control"],
+        "private, max-age=60, s-maxage=10, immutable"
+    );
+    assert_eq!(res.headers()["expires"], "Sun, 06 Nov 1994 08:49:37 GMT");
+    assert_eq!(res.headers()["surrogate-control"], "max-age=3600");
+
+    let mut headers = HeaderMap::new();
+    headers.insert("vary", "Accept-Encoding, accept-language".parse().unwrap());
+    CachePolicy::default()
+        .with_vary("Accept-Language")
+        .with_vary("Cookie")
+        .apply(&mut headers);
+    assert!(headers.get("cache-control").is_none());
+    let vary: Vec<_> = headers.get_all("vary").iter().collect();
+    assert_eq!(vary, ["Accept-Encoding, accept-language", "Cookie"]);
+
+    assert_eq!(
+        http_date(UNIX_EPOCH + Duration::from_secs(951_782_400)),
+        "Tue, 29 Feb 2000 00:00:00 GMT"
+    );
+}
@@ -0,0 +1,142 @@
+mod common;
+
+use axum::body::Body;
+use axum::http::{Request, StatusCode};
+use serde::Deserialize;
+use tower::ServiceExt;
+use utoipa::IntoResponses;
+use utoipa::{IntoParams, OpenApi};
+use utoipa_axum::router::OpenApiRouter;
+use utoipa_axum::routes;
+
+use utoipa_helper::doc_params::{DocCookies, DocHeaders, DocPath, DocQuery, ParamsRejection};
+
+#[derive(Deserialize, IntoParams)]
+struct ItemPath {
+    id: u32,
+}
+
+#[derive(Deserialize, IntoParams)]
+#[into_params(parameter_in = Path)]
+struct Filter {
+    limit: u32,
+}
+
+#[derive(Deserialize, IntoParams)]
+struct Trace {
+    #[serde(rename = "x-trace-id")]
+    trace_id: String,
+}
+
+#[derive(Deserialize, IntoParams)]
+struct Session {
+    session: String,
+}
+
+#[utoipa::path(
+    get,
+    path = "/items/{id}",
+    params(DocPath<ItemPath>, DocQuery<Filter>, DocHeaders<Trace>, DocCookies<Session>),
+    responses((status = OK, body = String), ParamsRejection)
+)]
+async fn get_item(
+    DocPath(path): DocPath<ItemPath>,
+    DocQuery(filter): DocQuery<Filter>,
+    DocHeaders(trace): DocHeaders<Trace>,
+    DocCookies(session): DocCookies<Session>,
+) -> String {
+    format!(
+        "{} {} {} {}",
+        path.id, filter.limit, trace.trace_id, session.session
+    )
+}
+
+#[derive(OpenApi)]
+struct ApiDoc;
+
+fn app() -> (axum::Router, utoipa::openapi::OpenApi) {
+    OpenApiRouter::with_openapi(ApiDoc::openapi())
+        .routes(routes!(get_item))
+        .split_for_parts()
+}
+
+async fn send(uri: &str, trace: Option<&str>) -> (StatusCode, serde_json::Value) {
+    let mut req = Request::get(uri).header("cookie", "theme=dark; session=abc%20d");
+    if let Some(trace) = trace {
+        req = req.header("x-trace-id", trace);
+    }
+    let (router, _) = app();
+    common::send(router, req.body(Body::empty()).unwrap()).await
+}
+
+#[tokio::test]
+async fn test_doc_params_extract() {
+    let (status, body) = send("/items/7?limit=5", Some("t1")).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body, "7 5 t1 abc%20d");
+
+    let (status, body) = send("/items/x?limit=5", Some("t1")).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(body["in"], "path");
+    assert_eq!(body["name"], "id");
+
+    let (status, body) = send("/items/7?limit=x", Some("t1")).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(body["in"], "query");
+    assert_eq!(body["name"], "limit");
+    assert_eq!(
+        body["detail"],
+        "invalid query parameter `limit`: invalid digit found in string"
+    );
+
+    let (status, body) = send("/items/7?limit=5", None).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(body["in"], "header");
+    assert_eq!(body["name"], "x-trace-id");
+    assert_eq!(body["status"], 400);
+}
+
+#[tokio::test]
+async fn test_doc_params_route_mismatch() {
+    async fn handler(DocPath((id, version)): DocPath<(u32, u32)>) -> String {
+        format!("{id} {version}")
+    }
+
+    let router = axum::Router::new().route("/items/{id}", axum::routing::get(handler));
+    let res = router
+        .oneshot(Request::get("/items/7").body(Body::empty()).unwrap())
+        .await
+        .unwrap();
+    assert_eq!(res.status(), StatusCode::INTERNAL_SERVER_ERROR);
+}
+
+#[test]
+fn test_doc_params_openapi() {
+    let (_, api) = app();
+    let api = serde_json::to_value(api).unwrap();
+    let operation = &api["paths"]["/items/{id}"]["get"];
+    let params: Vec<_> = operation["parameters"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|p| (p["name"].as_str().unwrap(), p["in"].as_str().unwrap()))
+        .collect();
+    assert_eq!(
+        params,
+        vec![
+            ("id", "path"),
+            ("limit", "query"),
+            ("x-trace-id", "header"),
+            ("session", "cookie"),
+        ]
+    );
+    assert_eq!(operation["parameters"][0]["required"], true);
+
+    let responses = serde_json::to_value(ParamsRejection::responses()).unwrap();
+    let content = &responses["400"]["content"]["application/problem+json"];
+    assert_eq!(content["examples"]["cookie"]["value"]["status"], 400);
+    assert!(
+        responses["500"]["content"]["application/problem+json"]["examples"]["MissingPathParams"]
+            .is_object()
+    );
+}
@@ -0,0 +1,67 @@
+mod common;
+
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+use serde::Serialize;
+use utoipa::{IntoResponses, PartialSchema, ToSchema};
+
+use utoipa_helper::{
+    UtoipaResponse, derive_envelope_meta, envelope::Envelope, json_response::JsonResponse,
+    problem_details::ProblemDetails,
+};
+
+#[derive(Clone, Serialize, ToSchema)]
+struct User {
+    id: u64,
+}
+
+#[derive(Clone, Serialize, ToSchema)]
+struct RequestMeta {
+    request_id: String,
+}
+
+derive_envelope_meta!(RequestMeta);
+
+#[derive(UtoipaResponse)]
+#[response(status = OK, description = "A user", envelope)]
+struct UserResponse(User);
+
+#[tokio::test]
+async fn test_envelope_response() {
+    let res = JsonResponse::new(
+        Envelope::new(User { id: 1 })
+            .with_meta(RequestMeta {
+                request_id: "r1".into(),
+            })
+            .with_error(ProblemDetails::new(StatusCode::PARTIAL_CONTENT)),
+    )
+    .into_response();
+    let body = common::body_json(res).await;
+    assert_eq!(body["data"]["id"], 1);
+    assert_eq!(body["meta"]["request_id"], "r1");
+    assert_eq!(body["errors"][0]["status"], 206);
+
+    let res = UserResponse::from(User { id: 2 }).into_response();
+    assert_eq!(res.headers()["content-type"], "application/json");
+    let body = common::body_json(res).await;
+    assert_eq!(body, serde_json::json!({"data": {"id": 2}}));
+}
+
+#[test]
+fn test_envelope_schema() {
+    assert_eq!(Envelope::<User>::name(), "Envelope_User");
+    assert_eq!(Envelope::<User, ()>::name(), "Envelope_User");
+    assert_eq!(
+        Envelope::<User, RequestMeta>::name(),
+        "Envelope_User_RequestMeta"
+    );
+    let schema = serde_json::to_value(Envelope::<User>::schema()).unwrap();
+    assert_eq!(schema["required"][0], "data");
+    assert!(schema["properties"].get("meta").is_none());
+    let schema = serde_json::to_value(Envelope::<User, RequestMeta>::schema()).unwrap();
+    assert!(schema["properties"]["meta"].is_object());
+
+    let responses = serde_json::to_value(UserResponse::responses()).unwrap();
+    let schema = &responses["200"]["content"]["application/json"]["schema"];
+    assert!(schema["properties"]["data"]["properties"]["id"].is_object());
+}
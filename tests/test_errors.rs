@@ -0,0 +1,267 @@
+mod common;
+
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+use serde::Serialize;
+use serde_json::json;
+use utoipa::openapi::RefOr;
+use utoipa::{IntoResponses, ToSchema};
+
+use tower::ServiceExt;
+use utoipa_helper::{
+    UtoipaError, UtoipaIntoResponse,
+    accept::Accept,
+    negotiated_error::{NegotiatedError, negotiate_errors},
+    problem_details::ProblemDetails,
+};
+
+#[allow(dead_code)]
+#[derive(Debug, UtoipaError)]
+enum ApiError {
+    #[problem(status = NOT_FOUND, type = "https://example.com/problems/missing", title = "Missing")]
+    Missing { id: u32 },
+    #[problem(status = "CONFLICT")]
+    Conflict(String),
+    #[problem(status = 409, title = "Stale")]
+    Stale,
+    #[problem(status = INTERNAL_SERVER_ERROR)]
+    Database(String),
+}
+
+impl std::fmt::Display for ApiError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Missing { id } => write!(f, "item {id} does not exist"),
+            Self::Conflict(reason) => write!(f, "conflict: {reason}"),
+            Self::Stale => write!(f, "stale"),
+            Self::Database(e) => write!(f, "database error: {e}"),
+        }
+    }
+}
+
+#[tokio::test]
+async fn test_problem_details_error() {
+    let res = ApiError::Missing { id: 7 }.into_response();
+    assert_eq!(res.status(), StatusCode::NOT_FOUND);
+    assert_eq!(
+        res.headers().get("content-type").unwrap(),
+        "application/problem+json"
+    );
+    assert_eq!(
+        common::body_json(res).await,
+        json!({
+            "type": "https://example.com/problems/missing",
+            "title": "Missing",
+            "status": 404,
+            "detail": "item 7 does not exist",
+        })
+    );
+
+    let res = ApiError::Conflict("busy".into()).into_response();
+    assert_eq!(res.status(), StatusCode::CONFLICT);
+    let problem: ProblemDetails = serde_json::from_value(common::body_json(res).await).unwrap();
+    assert_eq!(problem.problem_type, "about:blank");
+    assert_eq!(problem.title.as_deref(), Some("Conflict"));
+
+    let res = ApiError::Database("connection to 10.0.0.3 refused".into()).into_response();
+    assert_eq!(res.status(), StatusCode::INTERNAL_SERVER_ERROR);
+    assert_eq!(
+        common::body_json(res).await,
+        json!({
+            "type": "about:blank",
+            "title": "Internal Server Error",
+            "status": 500,
+        })
+    );
+
+    let responses = serde_json::to_value(ApiError::responses()).unwrap();
+    assert_eq!(responses["404"]["description"], "Missing");
+    assert_eq!(responses["409"]["description"], "Conflict; Stale");
+    let examples = &responses["409"]["content"]["application/problem+json"]["examples"];
+    assert_eq!(examples["Stale"]["value"]["title"], "Stale");
+    assert_eq!(examples["Conflict"]["value"]["status"], 409);
+}
+
+#[tokio::test]
+async fn test_problem_details_extensions() {
+    let res = ProblemDetails::new(StatusCode::TOO_MANY_REQUESTS)
+        .with_detail("slow down")
+        .with_instance("/quota/1")
+        .with_extension("retry_after", 30)
+        .into_response();
+    assert_eq!(res.status(), StatusCode::TOO_MANY_REQUESTS);
+    assert_eq!(
+        common::body_json(res).await,
+        json!({
+            "type": "about:blank",
+            "title": "Too Many Requests",
+            "status": 429,
+            "detail": "slow down",
+            "instance": "/quota/1",
+            "retry_after": 30,
+        })
+    );
+}
+
+#[derive(Serialize, ToSchema)]
+struct Conflict {
+    current_version: u32,
+}
+
+#[allow(dead_code)]
+#[derive(IntoResponses, UtoipaIntoResponse)]
+enum ItemError {
+    #[response(status = NOT_FOUND, description = "Item not found")]
+    NotFound,
+    #[response(status = 409, description = "Version conflict")]
+    Conflict(#[to_schema] Conflict),
+    #[response(status = BAD_REQUEST, description = "Invalid name", content_type = "text/plain")]
+    InvalidName(String),
+    #[response(
+        status = StatusCode::UNPROCESSABLE_ENTITY,
+        description = "Validation failed",
+        headers(("x-request-id" = String, description = "request id"))
+    )]
+    #[serde(rename_all = "camelCase")]
+    Invalid {
+        field_name: String,
+        #[serde(rename = "why")]
+        reason: String,
+    },
+}
+
+#[tokio::test]
+async fn test_utoipa_into_response() {
+    let responses = ItemError::responses();
+    let cases = [
+        (ItemError::NotFound, StatusCode::NOT_FOUND, None, None),
+        (
+            ItemError::Conflict(Conflict { current_version: 3 }),
+            StatusCode::CONFLICT,
+            Some("application/json"),
+            Some(json!({"current_version": 3})),
+        ),
+        (
+            ItemError::InvalidName("x".into()),
+            StatusCode::BAD_REQUEST,
+            Some("text/plain"),
+            None,
+        ),
+        (
+            ItemError::Invalid {
+                field_name: "name".into(),
+                reason: "too short".into(),
+            },
+            StatusCode::UNPROCESSABLE_ENTITY,
+            Some("application/json"),
+            Some(json!({"fieldName": "name", "why": "too short"})),
+        ),
+    ];
+    for (error, status, content_type, body) in cases {
+        let res = error.into_response();
+        assert_eq!(res.status(), status);
+        let RefOr::T(documented) = &responses[status.as_str()] else {
+            panic!("expected inline response");
+        };
+        let header = res
+            .headers()
+            .get("content-type")
+            .map(|h| h.to_str().unwrap().to_string());
+        assert_eq!(header.as_deref(), content_type);
+        if let Some(content_type) = content_type {
+            assert!(documented.content.contains_key(content_type));
+        } else {
+            assert!(documented.content.is_empty());
+        }
+        if let Some(body) = body {
+            let schema = serde_json::to_value(&documented.content[content_type.unwrap()]).unwrap();
+            for key in body.as_object().unwrap().keys() {
+                assert!(
+                    schema["schema"]["properties"].get(key).is_some(),
+                    "{key} undocumented"
+                );
+            }
+            assert_eq!(common::body_json(res).await, body);
+        }
+    }
+}
+
+#[tokio::test]
+async fn test_negotiated_error() {
+    async fn missing() -> Result<String, NegotiatedError<ApiError>> {
+        Err(ApiError::Missing { id: 1 }.into())
+    }
+
+    let router = axum::Router::new()
+        .route("/", axum::routing::get(missing))
+        .layer(axum::middleware::from_fn(negotiate_errors));
+
+    let request = |accept: Option<&str>| {
+        let mut request = axum::http::Request::builder().uri("/");
+        if let Some(accept) = accept {
+            request = request.header("accept", accept);
+        }
+        request.body(axum::body::Body::empty()).unwrap()
+    };
+
+    let browser = "text/html,application/xhtml+xml,application/xml;q=0.9,*/*;q=0.8";
+    let res = router
+        .clone()
+        .oneshot(request(Some(browser)))
+        .await
+        .unwrap();
+    assert_eq!(res.status(), StatusCode::NOT_FOUND);
+    assert_eq!(
+        res.headers().get("content-type").unwrap(),
+        "text/html; charset=utf-8"
+    );
+    let body = common::body_bytes(res).await;
+    let body = std::str::from_utf8(&body).unwrap();
+    assert!(body.contains("<h1>Missing</h1><p>item 1 does not exist</p>"));
+
+    for accept in [None, Some("application/json"), Some("text/html;q=0.5, */*")] {
+        let res = router.clone().oneshot(request(accept)).await.unwrap();
+        assert_eq!(res.status(), StatusCode::NOT_FOUND);
+        assert_eq!(
+            res.headers().get("content-type").unwrap(),
+            "application/problem+json"
+        );
+        assert_eq!(
+            common::body_json(res).await["detail"],
+            "item 1 does not exist"
+        );
+    }
+
+    let responses = serde_json::to_value(NegotiatedError::<ApiError>::responses()).unwrap();
+    let content = &responses["404"]["content"];
+    assert_eq!(content["text/html"]["schema"], json!({"type": "string"}));
+    assert!(content.get("application/problem+json").is_some());
+}
+
+#[test]
+fn test_accept_quality() {
+    let accept = Accept::parse("text/*;q=0.5, text/html, application/json;q=0");
+    assert_eq!(accept.quality("text/html"), 1.0);
+    assert_eq!(accept.quality("text/plain"), 0.5);
+    assert_eq!(accept.quality("application/json"), 0.0);
+    assert_eq!(
+        accept.preferred(&["application/json", "text/plain", "text/html"]),
+        Some("text/html")
+    );
+    assert_eq!(accept.preferred(&["application/json"]), None);
+    assert_eq!(
+        Accept::default().preferred(&["application/json", "text/html"]),
+        Some("application/json")
+    );
+}
+
+#[test]
+fn test_variant_serialize_failure() {
+    let body = std::collections::BTreeMap::from([((1, 2), 3)]);
+    let res = utoipa_helper::variant_response::json_response(StatusCode::CONFLICT, &body);
+    assert_eq!(res.status(), StatusCode::INTERNAL_SERVER_ERROR);
+    assert_eq!(
+        res.headers().get("content-type").unwrap(),
+        "application/problem+json"
+    );
+}
@@ -0,0 +1,82 @@
+mod common;
+
+use axum::response::IntoResponse;
+use serde::Serialize;
+use serde_json::json;
+use utoipa::{IntoResponses, PartialSchema, ToSchema};
+
+use utoipa_helper::{
+    UtoipaResponse,
+    hypermedia::{Hal, HalLink, JsonApi, RelationshipData, ResourceIdentifier},
+    json_response::JsonResponse,
+};
+
+#[derive(Clone, Serialize, ToSchema)]
+struct Order {
+    total: u64,
+}
+
+#[derive(UtoipaResponse)]
+#[response(status = OK, content = "application/hal+json", description = "Order")]
+#[rustfmt::skip]
+struct OrderResponse(JsonResponse::<Hal<Order>>);
+
+#[derive(UtoipaResponse)]
+#[response(status = OK, content = "application/vnd.api+json", description = "Order document")]
+#[rustfmt::skip]
+struct OrderDocument(JsonResponse::<JsonApi<Order>>);
+
+#[tokio::test]
+async fn test_hal() {
+    let hal = Hal::new(Order { total: 10 }, "/orders/1")
+        .with_link("customer", HalLink::new("/customers/7"))
+        .with_embedded("items", json!([{"sku": "a"}]));
+    let res = OrderResponse::from(JsonResponse::new(hal.clone())).into_response();
+    assert_eq!(
+        res.headers()["content-type"],
+        "application/hal+json; charset=utf-8"
+    );
+    let body = common::body_json(res).await;
+    assert_eq!(body["total"], 10);
+    assert_eq!(body["_links"]["self"]["href"], "/orders/1");
+    assert_eq!(body["_embedded"]["items"][0]["sku"], "a");
+
+    let res = hal.into_response();
+    assert_eq!(res.headers()["content-type"], "application/hal+json");
+
+    assert_eq!(Hal::<Order>::name(), "Hal_Order");
+    let responses = serde_json::to_value(OrderResponse::responses()).unwrap();
+    let schema = &responses["200"]["content"]["application/hal+json"]["schema"];
+    assert!(schema["allOf"][1]["properties"]["_links"].is_object());
+}
+
+#[tokio::test]
+async fn test_json_api() {
+    let doc = JsonApi::new("orders", "1", Order { total: 10 })
+        .with_relationship(
+            "customer",
+            RelationshipData::One(Some(ResourceIdentifier::new("customers", "7"))),
+        )
+        .with_link("self", "/orders/1");
+    let res = OrderDocument::from(JsonResponse::new(doc.clone())).into_response();
+    assert_eq!(res.headers()["content-type"], "application/vnd.api+json");
+    let res = doc.into_response();
+    assert_eq!(res.headers()["content-type"], "application/vnd.api+json");
+    let body = common::body_json(res).await;
+    assert_eq!(body["data"]["type"], "orders");
+    assert_eq!(body["data"]["attributes"]["total"], 10);
+    assert_eq!(
+        body["data"]["relationships"]["customer"]["data"],
+        json!({"type": "customers", "id": "7"})
+    );
+    assert_eq!(body["links"]["self"], "/orders/1");
+
+    assert_eq!(JsonApi::<Order>::name(), "JsonApi_Order");
+    let responses = serde_json::to_value(JsonApi::<Order>::responses()).unwrap();
+    let schema = &responses["200"]["content"]["application/vnd.api+json"]["schema"];
+    assert!(schema["properties"]["data"]["properties"]["attributes"].is_object());
+
+    let mut schemas = Vec::new();
+    JsonApi::<Order>::schemas(&mut schemas);
+    assert!(schemas.iter().any(|(name, _)| name == "ResourceIdentifier"));
+}
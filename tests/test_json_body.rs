@@ -0,0 +1,83 @@
+mod common;
+
+use axum::Router;
+use axum::body::Body;
+use axum::extract::DefaultBodyLimit;
+use axum::http::{Request, StatusCode};
+use axum::routing::post;
+use serde::Deserialize;
+use utoipa::{IntoResponses, ToSchema};
+
+use utoipa_helper::json_body::{JsonBody, JsonBodyRejection};
+
+#[derive(Deserialize, ToSchema)]
+struct Address {
+    zip: u32,
+}
+
+#[derive(Deserialize, ToSchema)]
+struct NewUser {
+    name: String,
+    address: Address,
+}
+
+async fn create_user(JsonBody(user): JsonBody<NewUser>) -> String {
+    format!("{} {}", user.name, user.address.zip)
+}
+
+fn app() -> Router {
+    Router::new()
+        .route("/users", post(create_user))
+        .layer(DefaultBodyLimit::max(64))
+}
+
+async fn send(content_type: Option<&str>, body: &str) -> (StatusCode, serde_json::Value) {
+    let mut req = Request::post("/users");
+    if let Some(content_type) = content_type {
+        req = req.header("content-type", content_type);
+    }
+    common::send(app(), req.body(Body::from(body.to_string())).unwrap()).await
+}
+
+#[tokio::test]
+async fn test_json_body() {
+    let (status, _) = send(
+        Some("application/json; charset=utf-8"),
+        r#"{"name": "a", "address": {"zip": 1}}"#,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+
+    let (status, body) = send(None, "{}").await;
+    assert_eq!(status, StatusCode::UNSUPPORTED_MEDIA_TYPE);
+    assert_eq!(body["status"], 415);
+
+    let (status, body) = send(Some("application/json"), r#"{"name": "a""#).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(body["line"], 1);
+
+    let (status, body) = send(
+        Some("application/json"),
+        r#"{"name": "a", "address": {"zip": "x"}}"#,
+    )
+    .await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(body["path"], "address.zip");
+
+    let (status, body) = send(Some("application/json"), &" ".repeat(100)).await;
+    assert_eq!(status, StatusCode::PAYLOAD_TOO_LARGE);
+    assert_eq!(body["status"], 413);
+}
+
+#[test]
+fn test_json_body_docs() {
+    assert_eq!(JsonBody::<NewUser>::name(), "NewUser");
+    let body = serde_json::to_value(JsonBody::<NewUser>::request_body()).unwrap();
+    assert_eq!(body["required"], true);
+    assert!(body["content"]["application/json"]["schema"]["properties"]["address"].is_object());
+
+    let responses = JsonBodyRejection::responses();
+    for status in ["400", "413", "415", "422"] {
+        assert!(responses.contains_key(status), "{status}");
+    }
+}
@@ -0,0 +1,48 @@
+use serde::Serialize;
+use std::borrow::Cow;
+use utoipa::ToSchema;
+
+use utoipa_helper::{
+    envelope::Envelope,
+    json_response::JsonResponse,
+    pagination::Page,
+    schema_name::{NamingStrategy, set_naming_strategy},
+};
+
+#[derive(Serialize, ToSchema)]
+struct User {
+    id: u64,
+}
+
+/// `Envelope<User>` is `UserEnvelope`, `Page<User>` is `PageOfUser` and
+/// `JsonResponse<User>` is `UserJson`.
+struct SuffixNaming;
+
+impl NamingStrategy for SuffixNaming {
+    fn schema_name(wrapper: &str, params: &[Cow<'static, str>]) -> String {
+        params.concat() + wrapper
+    }
+
+    fn wrapper_name(wrapper: &str, param: Cow<'static, str>) -> Cow<'static, str> {
+        if wrapper == "Page" {
+            format!("PageOf{param}").into()
+        } else {
+            Self::schema_name(wrapper, &[param]).into()
+        }
+    }
+
+    fn transparent_name(name: Cow<'static, str>) -> Cow<'static, str> {
+        format!("{name}Json").into()
+    }
+}
+
+#[test]
+fn test_naming_strategy() {
+    set_naming_strategy::<SuffixNaming>();
+    let again = std::panic::catch_unwind(set_naming_strategy::<SuffixNaming>);
+    assert!(again.is_err());
+
+    assert_eq!(Envelope::<User>::name(), "UserEnvelope");
+    assert_eq!(Page::<User>::name(), "PageOfUser");
+    assert_eq!(JsonResponse::<User>::name(), "UserJson");
+}
@@ -0,0 +1,116 @@
+mod common;
+
+use axum::http::{Request, StatusCode};
+use serde::Serialize;
+use serde_json::json;
+use tower::ServiceExt;
+use utoipa::{IntoResponses, ToSchema};
+
+use utoipa_helper::{
+    accept::Accept,
+    derive_response_description,
+    negotiated_response::NegotiatedResponse,
+    status_code_trait::{StatusCode2XX, StatusCodeOk},
+};
+
+#[derive(Serialize, ToSchema)]
+struct Item {
+    id: u32,
+    name: String,
+}
+
+struct ItemDescription {}
+
+derive_response_description!(ItemDescription, "An item");
+
+type ItemResponse = NegotiatedResponse<Item, StatusCodeOk, ItemDescription>;
+
+async fn get_item(accept: Accept) -> ItemResponse {
+    let item = Item {
+        id: 1,
+        name: "widget".into(),
+    };
+    NegotiatedResponse::new(accept, item)
+}
+
+async fn request(accept: &str) -> axum::response::Response {
+    let router = axum::Router::new().route("/", axum::routing::get(get_item));
+    let request = Request::builder()
+        .uri("/")
+        .header("accept", accept)
+        .body(axum::body::Body::empty())
+        .unwrap();
+    router.oneshot(request).await.unwrap()
+}
+
+#[tokio::test]
+async fn test_negotiated_json() {
+    let res = request("text/html, application/*;q=0.5").await;
+    assert_eq!(res.status(), StatusCode::OK);
+    assert_eq!(
+        res.headers().get("content-type").unwrap(),
+        "application/json"
+    );
+    assert_eq!(res.headers().get("vary").unwrap(), "accept");
+    let body = common::body_bytes(res).await;
+    assert_eq!(
+        serde_json::from_slice::<serde_json::Value>(&body).unwrap(),
+        json!({"id": 1, "name": "widget"})
+    );
+
+    let res = request("text/html, application/json;q=0").await;
+    assert_eq!(res.status(), StatusCode::NOT_ACCEPTABLE);
+    assert_eq!(
+        res.headers().get("content-type").unwrap(),
+        "application/problem+json"
+    );
+}
+
+#[cfg(all(feature = "yaml", feature = "xml", feature = "msgpack"))]
+#[tokio::test]
+async fn test_negotiated_formats() {
+    let res = request("application/json;q=0.5, application/yaml").await;
+    assert_eq!(
+        res.headers().get("content-type").unwrap(),
+        "application/yaml"
+    );
+    let body = common::body_bytes(res).await;
+    assert_eq!(&body[..], b"id: 1\nname: widget\n");
+
+    let res = request("application/xml").await;
+    let body = common::body_bytes(res).await;
+    assert_eq!(&body[..], b"<Item><id>1</id><name>widget</name></Item>");
+
+    let res = request("application/msgpack").await;
+    assert_eq!(
+        res.headers().get("content-type").unwrap(),
+        "application/msgpack"
+    );
+    let body = common::body_bytes(res).await;
+    let item: serde_json::Value = rmp_serde::from_slice(&body).unwrap();
+    assert_eq!(item, json!({"id": 1, "name": "widget"}));
+}
+
+#[test]
+fn test_negotiated_responses() {
+    let responses = serde_json::to_value(ItemResponse::responses()).unwrap();
+    assert_eq!(responses["200"]["description"], "An item");
+    let content = responses["200"]["content"].as_object().unwrap();
+    let media_types: Vec<_> = content.keys().map(String::as_str).collect();
+    assert!(media_types.contains(&"application/json"));
+    #[cfg(feature = "yaml")]
+    assert!(media_types.contains(&"application/yaml"));
+    for value in content.values() {
+        assert_eq!(value["schema"]["required"], json!(["id", "name"]));
+    }
+    assert!(responses["406"]["content"]["application/problem+json"].is_object());
+}
+
+#[test]
+fn test_negotiated_range_responses() {
+    type RangeResponse = NegotiatedResponse<Item, StatusCode2XX, ItemDescription>;
+
+    let responses = serde_json::to_value(RangeResponse::responses()).unwrap();
+    assert_eq!(responses["2XX"]["description"], "An item");
+    assert!(responses.get("500").is_none());
+}
@@ -0,0 +1,78 @@
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+use serde::Serialize;
+use utoipa::{IntoResponses, PartialSchema, ToSchema};
+
+use utoipa_helper::{
+    UtoipaResponse,
+    html_response::HtmlResponse,
+    json_response::JsonResponse,
+    one_of::{OneOf2, OneOf3},
+};
+
+#[derive(Serialize, ToSchema)]
+struct Item {
+    id: u32,
+}
+
+#[derive(UtoipaResponse)]
+#[response(status = "CREATED", content = "application/json", description = "Created")]
+#[rustfmt::skip]
+struct CreatedResponse(JsonResponse::<Item>);
+
+#[derive(UtoipaResponse)]
+#[response(status = "200", content = "application/json", description = "Existing item")]
+#[rustfmt::skip]
+struct ExistingResponse(JsonResponse::<Item>);
+
+#[derive(UtoipaResponse)]
+#[response(status = "200", content = "text/html", description = "Item page")]
+#[rustfmt::skip]
+struct ItemPageResponse(HtmlResponse::<String>);
+
+#[test]
+fn test_one_of_responses() {
+    type Upsert = OneOf3<CreatedResponse, ExistingResponse, ItemPageResponse>;
+
+    let res = Upsert::A(JsonResponse::new(Item { id: 1 }).into()).into_response();
+    assert_eq!(res.status(), StatusCode::CREATED);
+    let res = Upsert::C(HtmlResponse::new("<p>1</p>".to_string()).into()).into_response();
+    assert_eq!(res.status(), StatusCode::OK);
+
+    let responses = serde_json::to_value(Upsert::responses()).unwrap();
+    assert_eq!(responses["201"]["description"], "Created");
+    assert_eq!(
+        responses["200"]["description"],
+        "Existing item or Item page"
+    );
+    assert!(responses["200"]["content"]["application/json"].is_object());
+    assert!(responses["200"]["content"]["text/html"].is_object());
+}
+
+#[derive(UtoipaResponse)]
+#[response(status = "200", content = "application/json", description = "Item name")]
+#[rustfmt::skip]
+struct ItemNameResponse(JsonResponse::<String>);
+
+#[test]
+fn test_one_of_conflict() {
+    let Ok(responses) = OneOf2::<ExistingResponse, ExistingResponse>::try_responses() else {
+        panic!("identical responses should merge");
+    };
+    assert!(responses == ExistingResponse::responses());
+
+    type Conflicting = OneOf2<ExistingResponse, ItemNameResponse>;
+    let Err(conflict) = Conflicting::try_responses() else {
+        panic!("differing bodies should conflict");
+    };
+    assert_eq!(
+        conflict.to_string(),
+        "conflicting application/json content documented for status 200"
+    );
+}
+
+#[test]
+#[should_panic(expected = "conflicting application/json content documented for status 200")]
+fn test_one_of_conflict_responses() {
+    OneOf2::<ExistingResponse, ItemNameResponse>::responses();
+}
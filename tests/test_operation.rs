@@ -0,0 +1,94 @@
+#![cfg(feature = "operations")]
+
+mod common;
+
+use axum::body::Body;
+use axum::http::{Request, StatusCode};
+use axum::response::IntoResponse;
+use serde::Serialize;
+use serde_json::Value;
+use std::time::Duration;
+use utoipa::ToSchema;
+
+use utoipa_helper::operation::{OperationRegistry, OperationStatus};
+
+#[derive(Clone, Serialize, ToSchema)]
+struct Report {
+    rows: u32,
+}
+
+async fn get_json(router: axum::Router, uri: &str) -> (StatusCode, Value) {
+    common::send(router, Request::get(uri).body(Body::empty()).unwrap()).await
+}
+
+#[tokio::test]
+async fn test_operation_registry() {
+    let registry = OperationRegistry::<Report>::new();
+    let (id, accepted) = registry.accepted();
+    let location = registry.location(&id);
+    let res = accepted.into_response();
+    assert_eq!(res.headers()["location"], location.as_str());
+
+    let (router, api) = registry.router::<()>().split_for_parts();
+    assert!(api.paths.paths.contains_key("/operations/{id}"));
+    let operation = api.paths.paths["/operations/{id}"].get.as_ref().unwrap();
+    assert_eq!(
+        operation.operation_id.as_deref(),
+        Some("get_operations_status")
+    );
+
+    let (status, body) = get_json(router.clone(), &location).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["status"], "pending");
+
+    assert!(registry.update(
+        &id,
+        OperationStatus::Succeeded {
+            result: Report { rows: 3 }
+        }
+    ));
+    let (_, body) = get_json(router.clone(), &location).await;
+    assert_eq!(body["status"], "succeeded");
+    assert_eq!(body["result"]["rows"], 3);
+
+    let (status, body) = get_json(router, "/operations/missing").await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    assert_eq!(body["status"], 404);
+    assert!(!registry.update("missing", OperationStatus::Running));
+    assert_eq!(OperationStatus::<Report>::name(), "OperationStatus_Report");
+}
+
+#[test]
+fn test_operation_ttl() {
+    let registry = OperationRegistry::<u32>::new().with_ttl(Duration::ZERO);
+    let running = registry.start();
+    let done = registry.start();
+    registry.update(&running, OperationStatus::Running);
+    registry.update(&done, OperationStatus::Succeeded { result: 1 });
+    assert_eq!(registry.get(&running), Some(OperationStatus::Running));
+    assert_eq!(registry.get(&done), None);
+    assert!(!registry.update(&done, OperationStatus::Running));
+    assert_eq!(registry.remove(&done), None);
+    registry.start();
+    assert_eq!(registry.remove(&running), Some(OperationStatus::Running));
+}
+
+#[test]
+fn test_operation_ids() {
+    let reports = OperationRegistry::<Report>::with_base_path("/reports/jobs/");
+    let exports = OperationRegistry::<Report>::with_base_path("/exports");
+    assert_eq!(reports.operation_id(), "get_reports_jobs_status");
+    assert_eq!(exports.operation_id(), "get_exports_status");
+
+    let (_, api) = reports
+        .router::<()>()
+        .merge(exports.router())
+        .split_for_parts();
+    let ids: Vec<_> = api
+        .paths
+        .paths
+        .values()
+        .filter_map(|item| item.get.as_ref()?.operation_id.clone())
+        .collect();
+    assert_eq!(ids, ["get_exports_status", "get_reports_jobs_status"]);
+}
@@ -0,0 +1,101 @@
+mod common;
+
+use axum::Router;
+use axum::body::Body;
+use axum::http::{Request, StatusCode};
+use axum::response::IntoResponse;
+use axum::routing::get;
+use serde::Serialize;
+use tower::ServiceExt;
+use utoipa::{IntoParams, IntoResponses, PartialSchema, ToSchema};
+
+use utoipa_helper::pagination::{Page, PageParams, PaginatedResponse};
+
+#[derive(Clone, Serialize, ToSchema)]
+struct User {
+    id: u64,
+}
+
+async fn list_users(params: PageParams) -> PaginatedResponse<User> {
+    let total = 45;
+    let users = (params.offset()..total)
+        .take(params.limit() as usize)
+        .map(|id| User { id })
+        .collect();
+    PaginatedResponse::from_offset(users, total, &params, "/users")
+}
+
+async fn send(uri: &str) -> axum::response::Response {
+    Router::new()
+        .route("/users", get(list_users))
+        .oneshot(Request::get(uri).body(Body::empty()).unwrap())
+        .await
+        .unwrap()
+}
+
+#[tokio::test]
+async fn test_paginated_response() {
+    let res = send("/users?offset=20&limit=10").await;
+    assert_eq!(res.status(), StatusCode::OK);
+    assert_eq!(
+        res.headers()["link"],
+        "</users?offset=30&limit=10>; rel=\"next\", </users?offset=10&limit=10>; rel=\"prev\", \
+         </users?offset=0&limit=10>; rel=\"first\", </users?offset=40&limit=10>; rel=\"last\""
+    );
+    let body = common::body_json(res).await;
+    assert_eq!(body["items"].as_array().unwrap().len(), 10);
+    assert_eq!(body["items"][0]["id"], 20);
+    assert_eq!(body["total"], 45);
+    assert_eq!(body["next"], "/users?offset=30&limit=10");
+
+    let res = send("/users?offset=40&limit=10").await;
+    let body = common::body_json(res).await;
+    assert!(body.get("next").is_none());
+    assert_eq!(body["prev"], "/users?offset=30&limit=10");
+
+    let res = send("/users?limit=500").await;
+    assert_eq!(res.status(), StatusCode::BAD_REQUEST);
+    assert_eq!(res.headers()["content-type"], "application/problem+json");
+
+    let res = send("/users?cursor=abc&offset=1").await;
+    assert_eq!(res.status(), StatusCode::BAD_REQUEST);
+}
+
+#[test]
+fn test_page_documented() {
+    assert_eq!(Page::<User>::name(), "Page_User");
+    let schema = serde_json::to_value(Page::<User>::schema()).unwrap();
+    assert_eq!(schema["properties"]["items"]["type"], "array");
+
+    let responses = serde_json::to_value(PaginatedResponse::<User>::responses()).unwrap();
+    assert!(responses["200"]["headers"]["Link"].is_object());
+    assert!(responses["200"]["content"]["application/json"].is_object());
+
+    let params = serde_json::to_value(PageParams::into_params(|| None)).unwrap();
+    assert_eq!(params[2]["name"], "limit");
+    assert_eq!(params[2]["schema"]["maximum"], 100);
+
+    let res = PaginatedResponse::new(Page::new(vec![User { id: 1 }])).into_response();
+    assert!(res.headers().get("link").is_none());
+}
+
+#[test]
+fn test_page_links_extend_existing_query() {
+    let params = PageParams {
+        offset: Some(10),
+        limit: Some(10),
+        ..PageParams::default()
+    };
+    let items = vec![User { id: 10 }];
+    let res = PaginatedResponse::from_offset(items, 25, &params, "/users?role=admin");
+    assert_eq!(
+        res.link_header().unwrap(),
+        "</users?role=admin&offset=20&limit=10>; rel=\"next\", \
+         </users?role=admin&offset=0&limit=10>; rel=\"prev\", \
+         </users?role=admin&offset=0&limit=10>; rel=\"first\", \
+         </users?role=admin&offset=20&limit=10>; rel=\"last\""
+    );
+
+    let page = serde_json::to_value(Page::new(vec![User { id: 1 }])).unwrap();
+    assert_eq!(page, serde_json::json!({"items": [{"id": 1}]}));
+}
@@ -0,0 +1,165 @@
+use std::borrow::Cow;
+use utoipa::openapi::schema::{ObjectBuilder, Schema};
+use utoipa::openapi::{ComponentsBuilder, OpenApiBuilder, RefOr};
+use utoipa::{PartialSchema, ToSchema};
+
+use utoipa_helper::schema_name::{
+    DuplicateSchemaError, NamingStrategy, SchemaCollector, UnderscoreNaming, merge_openapi,
+    wrapper_name,
+};
+
+#[allow(dead_code)]
+#[derive(ToSchema)]
+struct User {
+    id: u64,
+}
+
+#[allow(dead_code)]
+#[derive(ToSchema)]
+struct Order {
+    total: u64,
+}
+
+fn wrap<T: ToSchema>() -> RefOr<Schema> {
+    ObjectBuilder::new().property("data", T::schema()).into()
+}
+
+/// Forwards the inner name, colliding with the inner type's component.
+struct Forwarding<T>(T);
+
+impl<T: ToSchema> PartialSchema for Forwarding<T> {
+    fn schema() -> RefOr<Schema> {
+        wrap::<T>()
+    }
+}
+
+impl<T: ToSchema> ToSchema for Forwarding<T> {
+    fn name() -> Cow<'static, str> {
+        T::name()
+    }
+}
+
+struct Envelope<T>(T);
+
+impl<T: ToSchema> PartialSchema for Envelope<T> {
+    fn schema() -> RefOr<Schema> {
+        wrap::<T>()
+    }
+}
+
+impl<T: ToSchema> ToSchema for Envelope<T> {
+    fn name() -> Cow<'static, str> {
+        wrapper_name::<T>("Envelope")
+    }
+}
+
+#[test]
+fn test_wrapper_names() {
+    assert_eq!(Envelope::<User>::name(), "Envelope_User");
+    assert_eq!(
+        UnderscoreNaming::schema_name("Pair", &["User".into(), "Order".into()]),
+        "Pair_User_Order"
+    );
+
+    let mut collector = SchemaCollector::default();
+    collector
+        .add::<User>()
+        .add::<Order>()
+        .add::<Envelope<User>>()
+        .add::<Envelope<Order>>()
+        .add::<User>();
+    let schemas = collector.finish().unwrap();
+    assert!(schemas.contains_key("Envelope_User"));
+    assert!(schemas.contains_key("Envelope_Order"));
+}
+
+#[test]
+fn test_duplicate_schemas() {
+    let mut collector = SchemaCollector::default();
+    collector.add::<User>().add::<Forwarding<User>>();
+    let err = collector.finish().err().unwrap();
+    assert_eq!(
+        err,
+        DuplicateSchemaError {
+            names: vec!["User".into()]
+        }
+    );
+    assert_eq!(err.to_string(), "conflicting schemas registered as User");
+
+    let components = |name: &str, schema: RefOr<Schema>| {
+        OpenApiBuilder::new()
+            .components(Some(ComponentsBuilder::new().schema(name, schema).build()))
+            .build()
+    };
+    let mut api = components("User", User::schema());
+    merge_openapi(&mut api, components("Order", Order::schema())).unwrap();
+    merge_openapi(&mut api, components("User", User::schema())).unwrap();
+    assert!(merge_openapi(&mut api, components("User", Order::schema())).is_err());
+    assert_eq!(api.components.unwrap().schemas.len(), 2);
+}
+
+#[cfg(feature = "router")]
+#[test]
+fn test_checked_router() {
+    use utoipa_helper::schema_name::CheckedRouter;
+
+    #[utoipa::path(get, path = "/user", responses((status = OK, body = User)))]
+    async fn get_user() {}
+
+    /// Registers `Order`'s schema as `User`.
+    struct Impostor;
+
+    impl PartialSchema for Impostor {
+        fn schema() -> RefOr<Schema> {
+            Order::schema()
+        }
+    }
+
+    impl ToSchema for Impostor {
+        fn name() -> Cow<'static, str> {
+            "User".into()
+        }
+    }
+
+    #[utoipa::path(get, path = "/impostor", responses((status = OK, body = Impostor)))]
+    async fn get_impostor() {}
+
+    let checked = || CheckedRouter::<()>::with_openapi(OpenApiBuilder::new().build());
+    let (_, api) = checked()
+        .routes(utoipa_axum::routes!(get_user))
+        .split_for_parts()
+        .unwrap();
+    assert!(api.components.unwrap().schemas.contains_key("User"));
+
+    let err = checked()
+        .routes(utoipa_axum::routes!(get_user))
+        .routes(utoipa_axum::routes!(get_impostor))
+        .split_for_parts()
+        .err()
+        .unwrap();
+    assert_eq!(err.names, vec!["User".to_string()]);
+
+    let (_, api) = checked()
+        .nest("/v1", checked().routes(utoipa_axum::routes!(get_user)))
+        .merge(checked().routes(utoipa_axum::routes!(get_user)))
+        .split_for_parts()
+        .unwrap();
+    assert!(api.paths.paths.contains_key("/v1/user"));
+    assert!(api.paths.paths.contains_key("/user"));
+
+    let err = checked()
+        .routes(utoipa_axum::routes!(get_user))
+        .nest("/v1", checked().routes(utoipa_axum::routes!(get_impostor)))
+        .split_for_parts()
+        .err()
+        .unwrap();
+    assert_eq!(err.names, vec!["User".to_string()]);
+
+    let err = checked()
+        .routes(utoipa_axum::routes!(get_user))
+        .merge(checked().routes(utoipa_axum::routes!(get_impostor)))
+        .split_for_parts()
+        .err()
+        .unwrap();
+    assert_eq!(err.names, vec!["User".to_string()]);
+}
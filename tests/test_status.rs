@@ -0,0 +1,219 @@
+mod common;
+
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+use futures_util::StreamExt;
+use futures_util::stream::{self, BoxStream};
+use serde::Serialize;
+use utoipa::{IntoResponses, PartialSchema, ToSchema};
+
+use std::time::Duration;
+use utoipa_helper::{
+    UtoipaResponse,
+    accepted_response::AcceptedResponse,
+    created_response::{CreatedResponse, LocationError, location_from_template},
+    derive_response_description,
+    empty_response::EmptyResponse,
+    html_response::HtmlResponse,
+    html_stream_response::HtmlStreamResponse,
+    json_response::JsonResponse,
+    redirect_response::{PermanentRedirectResponse, SeeOtherResponse},
+    status_code_trait::{StatusCodeValue, WithStatus},
+};
+
+#[derive(Serialize, ToSchema)]
+struct Failure {
+    message: String,
+}
+
+#[derive(UtoipaResponse)]
+#[response(status = "4XX", content = "application/json", description = "Client error")]
+#[rustfmt::skip]
+struct ClientErrorResponse(JsonResponse::<Failure>);
+
+#[derive(UtoipaResponse)]
+#[response(status = default, content = "application/json", description = "Unexpected error")]
+#[rustfmt::skip]
+struct DefaultResponse(JsonResponse::<Failure>);
+
+#[derive(UtoipaResponse)]
+#[response(status = NOT_FOUND, content = "application/json", description = "Missing")]
+#[rustfmt::skip]
+struct MissingResponse(JsonResponse::<Failure>);
+
+#[derive(UtoipaResponse)]
+#[response(status = NO_CONTENT, description = "Deleted")]
+#[rustfmt::skip]
+struct DeletedResponse(HtmlResponse::<String>);
+
+struct NotModified;
+derive_response_description!(NotModified, "Not modified");
+
+fn failure_body() -> Failure {
+    Failure {
+        message: "failed".into(),
+    }
+}
+
+fn failure() -> JsonResponse<Failure> {
+    JsonResponse::new(failure_body())
+}
+
+#[test]
+fn test_range_status() {
+    let responses = serde_json::to_value(ClientErrorResponse::responses()).unwrap();
+    assert_eq!(responses["4XX"]["description"], "Client error");
+
+    let res =
+        ClientErrorResponse::from(failure().with_status(StatusCode::CONFLICT)).into_response();
+    assert_eq!(res.status(), StatusCode::CONFLICT);
+
+    let res = ClientErrorResponse::from(failure()).into_response();
+    assert_eq!(res.status(), StatusCode::INTERNAL_SERVER_ERROR);
+}
+
+#[test]
+#[should_panic(expected = "status 200 OK is outside the documented 4XX range")]
+fn test_range_status_mismatch() {
+    let _ = ClientErrorResponse::from(failure().with_status(StatusCode::OK)).into_response();
+}
+
+#[derive(UtoipaResponse)]
+#[response(status = "2XX", content = "text/html", description = "Page")]
+#[rustfmt::skip]
+struct PartialPageResponse(WithStatus::<HtmlStreamResponse::<BoxStream<'static, String>>>);
+
+#[tokio::test]
+async fn test_range_status_with_status() {
+    let page = HtmlStreamResponse::new(stream::iter(["<p>part</p>".to_string()]).boxed());
+    let res = PartialPageResponse::from(WithStatus::new(page, StatusCode::PARTIAL_CONTENT))
+        .into_response();
+    assert_eq!(res.status(), StatusCode::PARTIAL_CONTENT);
+    assert_eq!(&common::body_bytes(res).await[..], b"<p>part</p>");
+
+    let responses = serde_json::to_value(PartialPageResponse::responses()).unwrap();
+    assert_eq!(responses["2XX"]["description"], "Page");
+}
+
+#[test]
+fn test_default_status() {
+    let responses = serde_json::to_value(DefaultResponse::responses()).unwrap();
+    assert_eq!(responses["default"]["description"], "Unexpected error");
+
+    let res = DefaultResponse::from(failure().with_status(StatusCode::BAD_GATEWAY)).into_response();
+    assert_eq!(res.status(), StatusCode::BAD_GATEWAY);
+
+    let res = DefaultResponse::from(failure()).into_response();
+    assert_eq!(res.status(), StatusCode::INTERNAL_SERVER_ERROR);
+}
+
+#[test]
+fn test_constant_status() {
+    let responses = serde_json::to_value(MissingResponse::responses()).unwrap();
+    assert_eq!(responses["404"]["description"], "Missing");
+
+    let res = MissingResponse::from(failure().with_status(StatusCode::OK)).into_response();
+    assert_eq!(res.status(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn test_no_content_status() {
+    let responses = serde_json::to_value(DeletedResponse::responses()).unwrap();
+    assert_eq!(responses["204"]["description"], "Deleted");
+    assert!(responses["204"].get("content").is_none());
+
+    let res = DeletedResponse::from(HtmlResponse::new("gone".to_string())).into_response();
+    assert_eq!(res.status(), StatusCode::NO_CONTENT);
+    assert!(res.headers().get("content-type").is_none());
+    let body = common::body_bytes(res).await;
+    assert!(body.is_empty());
+}
+
+#[test]
+fn test_empty_response() {
+    type Response = EmptyResponse<StatusCodeValue<304>, NotModified>;
+
+    let responses = serde_json::to_value(Response::responses()).unwrap();
+    assert_eq!(responses["304"]["description"], "Not modified");
+    assert!(responses["304"].get("content").is_none());
+
+    let res = Response::new().with_cookie("a=b").into_response();
+    assert_eq!(res.status(), StatusCode::NOT_MODIFIED);
+    assert_eq!(res.headers()["set-cookie"], "a=b");
+}
+
+struct LoggedOut;
+derive_response_description!(LoggedOut, "Logged out");
+
+#[test]
+fn test_redirect_response() {
+    let res = SeeOtherResponse::<LoggedOut>::new("/login")
+        .with_cookie("session=; Max-Age=0")
+        .into_response();
+    assert_eq!(res.status(), StatusCode::SEE_OTHER);
+    assert_eq!(res.headers()["location"], "/login");
+    assert_eq!(res.headers()["set-cookie"], "session=; Max-Age=0");
+
+    let responses = serde_json::to_value(SeeOtherResponse::<LoggedOut>::responses()).unwrap();
+    assert_eq!(responses["303"]["description"], "Logged out");
+    assert_eq!(
+        responses["303"]["headers"]["Location"]["schema"]["type"],
+        "string"
+    );
+
+    let res = PermanentRedirectResponse::<LoggedOut>::new("/new").into_response();
+    assert_eq!(res.status(), StatusCode::PERMANENT_REDIRECT);
+}
+
+#[derive(Serialize)]
+struct ItemPath {
+    group: String,
+    id: u32,
+}
+
+#[tokio::test]
+async fn test_created_response() {
+    let path = ItemPath {
+        group: "a b".into(),
+        id: 7,
+    };
+    let res = CreatedResponse::<Failure>::from_template(
+        failure_body(),
+        "/groups/{group}/items/{id}",
+        &path,
+    )
+    .unwrap()
+    .into_response();
+    assert_eq!(res.status(), StatusCode::CREATED);
+    assert_eq!(res.headers()["location"], "/groups/a%20b/items/7");
+
+    let err = location_from_template("/items/{missing}", &path).unwrap_err();
+    assert_eq!(err, LocationError::MissingParam("missing".into()));
+    let res = err.into_response();
+    assert_eq!(res.status(), StatusCode::INTERNAL_SERVER_ERROR);
+    assert_eq!(res.headers()["content-type"], "application/problem+json");
+    assert!(common::body_json(res).await.get("detail").is_none());
+    let responses = serde_json::to_value(LocationError::responses()).unwrap();
+    assert!(responses["500"]["content"]["application/problem+json"].is_object());
+
+    let responses = serde_json::to_value(CreatedResponse::<Failure>::responses()).unwrap();
+    assert!(responses["201"]["headers"]["Location"].is_object());
+    assert!(responses["201"]["content"]["application/json"]["schema"].is_object());
+}
+
+#[test]
+fn test_accepted_response() {
+    let res = AcceptedResponse::<Failure>::new(failure_body(), "/operations/1")
+        .with_retry_after(Duration::from_millis(1500))
+        .into_response();
+    assert_eq!(res.status(), StatusCode::ACCEPTED);
+    assert_eq!(res.headers()["location"], "/operations/1");
+    assert_eq!(res.headers()["retry-after"], "2");
+
+    let responses = serde_json::to_value(AcceptedResponse::<Failure>::responses()).unwrap();
+    assert!(responses["202"]["headers"]["Location"].is_object());
+    assert_eq!(
+        responses["202"]["headers"]["Retry-After"]["schema"]["type"],
+        "integer"
+    );
+}
@@ -0,0 +1,277 @@
+mod common;
+
+use axum::body::to_bytes;
+use axum::extract::FromRequestParts;
+use axum::response::IntoResponse;
+use futures_util::StreamExt;
+use futures_util::stream::{self, BoxStream};
+use serde::Serialize;
+use std::{borrow::Cow, time::Duration};
+use utoipa::openapi::RefOr;
+use utoipa::{IntoResponses, PartialSchema, ToSchema};
+
+use utoipa_helper::{
+    UtoipaResponse,
+    html_stream_response::HtmlStreamResponse,
+    json_array_stream_response::JsonArrayStreamResponse,
+    json_response::JsonResponse,
+    ndjson_response::{NdjsonErrorMode, NdjsonResponse},
+    sse_response::{LastEventId, SseEventTrait, SseResponse},
+};
+
+#[derive(Serialize, ToSchema)]
+struct Row {
+    id: u32,
+}
+
+type RowStream = BoxStream<'static, Result<Row, std::io::Error>>;
+
+#[derive(UtoipaResponse)]
+#[response(status = "OK", content = "application/x-ndjson", description = "rows")]
+#[rustfmt::skip]
+struct TestNdjsonResponse(NdjsonResponse::<RowStream>);
+
+fn rows(fail_after: Option<u32>) -> RowStream {
+    Box::pin(stream::iter((0..3).map(move |id| {
+        if Some(id) == fail_after {
+            Err(std::io::Error::other("database went away"))
+        } else {
+            Ok(Row { id })
+        }
+    })))
+}
+
+#[tokio::test]
+async fn test_ndjson_response() {
+    let res: TestNdjsonResponse = NdjsonResponse::new(rows(None)).into();
+    let res = res.into_response();
+    assert_eq!(
+        res.headers().get("content-type").unwrap(),
+        "application/x-ndjson; charset=utf-8"
+    );
+    let body = common::body_bytes(res).await;
+    assert_eq!(&body[..], b"{\"id\":0}\n{\"id\":1}\n{\"id\":2}\n");
+
+    let res = NdjsonResponse::new(rows(Some(1)))
+        .with_error_mode(NdjsonErrorMode::ErrorLine)
+        .into_response();
+    let body = common::body_bytes(res).await;
+    assert_eq!(
+        &body[..],
+        b"{\"id\":0}\n{\"error\":\"database went away\"}\n"
+    );
+
+    let res = NdjsonResponse::new(rows(Some(1))).into_response();
+    assert!(to_bytes(res.into_body(), usize::MAX).await.is_err());
+
+    let responses = TestNdjsonResponse::responses();
+    let RefOr::T(response) = &responses["200"] else {
+        panic!("expected inline response");
+    };
+    let content = &response.content["application/x-ndjson"];
+    assert_eq!(
+        serde_json::to_value(content.schema.as_ref().unwrap()).unwrap(),
+        serde_json::to_value(Row::schema()).unwrap()
+    );
+}
+
+#[derive(UtoipaResponse)]
+#[response(status = "OK", content = "application/json", description = "rows")]
+#[rustfmt::skip]
+struct TestJsonArrayResponse(JsonArrayStreamResponse::<BoxStream<'static, Row>>);
+
+#[derive(UtoipaResponse)]
+#[response(status = "OK", content = "application/json", description = "rows")]
+#[rustfmt::skip]
+struct TestJsonVecResponse(JsonResponse::<Vec<Row>>);
+
+#[tokio::test]
+async fn test_json_array_stream_response() {
+    let items = stream::iter((0..5).map(|id| Row { id })).boxed();
+    let res: TestJsonArrayResponse = JsonArrayStreamResponse::new(items)
+        .with_buffer_size(2)
+        .into();
+    let res = res.into_response();
+    assert_eq!(
+        res.headers().get("content-type").unwrap(),
+        "application/json; charset=utf-8"
+    );
+    let body = common::body_bytes(res).await;
+    let expected = serde_json::to_vec(&(0..5).map(|id| Row { id }).collect::<Vec<_>>()).unwrap();
+    assert_eq!(&body[..], &expected[..]);
+
+    let res = JsonArrayStreamResponse::new(stream::empty::<Row>()).into_response();
+    let body = common::body_bytes(res).await;
+    assert_eq!(&body[..], b"[]");
+
+    assert_eq!(
+        serde_json::to_value(TestJsonArrayResponse::responses()).unwrap(),
+        serde_json::to_value(TestJsonVecResponse::responses()).unwrap()
+    );
+}
+
+#[derive(Serialize, ToSchema)]
+struct Flaky {
+    #[serde(serialize_with = "fail_on_two")]
+    id: u32,
+}
+
+fn fail_on_two<S: serde::Serializer>(id: &u32, serializer: S) -> Result<S::Ok, S::Error> {
+    if *id == 2 {
+        return Err(serde::ser::Error::custom("unserializable"));
+    }
+    serializer.serialize_u32(*id)
+}
+
+#[tokio::test]
+async fn test_json_array_stream_serialization_error() {
+    let items = stream::iter((0..5).map(|id| Flaky { id }));
+    let res = JsonArrayStreamResponse::new(items)
+        .with_buffer_size(2)
+        .into_response();
+    let body = common::body_bytes(res).await;
+    assert_eq!(&body[..], br#"[{"id":0},{"id":1}]"#);
+}
+
+#[derive(Serialize, ToSchema)]
+#[serde(tag = "type")]
+enum Update {
+    Created { id: u32 },
+    Deleted { id: u32 },
+}
+
+impl SseEventTrait for Update {
+    const EVENT_NAMES: &'static [&'static str] = &["created", "deleted"];
+
+    fn event_name(&self) -> Option<Cow<'static, str>> {
+        match self {
+            Self::Created { .. } => Some("created".into()),
+            Self::Deleted { .. } => Some("deleted".into()),
+        }
+    }
+
+    fn event_id(&self) -> Option<String> {
+        match self {
+            Self::Created { id } | Self::Deleted { id } => Some(id.to_string()),
+        }
+    }
+}
+
+#[derive(Serialize, ToSchema)]
+struct Undeclared;
+
+impl SseEventTrait for Undeclared {
+    const EVENT_NAMES: &'static [&'static str] = &["declared"];
+
+    fn event_name(&self) -> Option<Cow<'static, str>> {
+        Some("undeclared".into())
+    }
+}
+
+#[tokio::test]
+#[should_panic(expected = "event `undeclared` is missing from EVENT_NAMES")]
+async fn test_sse_undeclared_event_name() {
+    let res = SseResponse::new(stream::iter([Undeclared])).into_response();
+    common::body_bytes(res).await;
+}
+
+#[derive(UtoipaResponse)]
+#[response(status = "OK", content = "text/event-stream", description = "updates")]
+#[rustfmt::skip]
+struct TestSseResponse(SseResponse::<BoxStream<'static, Update>>);
+
+#[tokio::test]
+async fn test_sse_response() {
+    let request = axum::http::Request::builder()
+        .header("Last-Event-ID", "1")
+        .body(())
+        .unwrap();
+    let (mut parts, ()) = request.into_parts();
+    let LastEventId(last_id) = LastEventId::from_request_parts(&mut parts, &())
+        .await
+        .unwrap();
+    let last_id: u32 = last_id.unwrap().parse().unwrap();
+
+    let updates = stream::iter([
+        Update::Created { id: 1 },
+        Update::Created { id: 2 },
+        Update::Deleted { id: 3 },
+    ])
+    .filter(move |update| {
+        let (Update::Created { id } | Update::Deleted { id }) = update;
+        std::future::ready(*id > last_id)
+    })
+    .boxed();
+    let res: TestSseResponse = SseResponse::new(updates)
+        .with_retry(Duration::from_secs(5))
+        .into();
+    let res = res.into_response();
+    assert_eq!(
+        res.headers().get("content-type").unwrap(),
+        "text/event-stream; charset=utf-8"
+    );
+    let body = common::body_bytes(res).await;
+    assert_eq!(
+        std::str::from_utf8(&body).unwrap(),
+        "data: {\"type\":\"Created\",\"id\":2}\nevent: created\nid: 2\nretry: 5000\n\n\
+         data: {\"type\":\"Deleted\",\"id\":3}\nevent: deleted\nid: 3\n\n"
+    );
+
+    let responses = TestSseResponse::responses();
+    let RefOr::T(response) = &responses["200"] else {
+        panic!("expected inline response");
+    };
+    let content = &response.content["text/event-stream"];
+    let schema = serde_json::to_value(content.schema.as_ref().unwrap()).unwrap();
+    assert_eq!(
+        schema["allOf"][0],
+        serde_json::to_value(Update::schema()).unwrap()
+    );
+    assert_eq!(
+        schema["x-sse-events"],
+        serde_json::json!(["created", "deleted"])
+    );
+    assert_eq!(schema["description"], "Events: created, deleted");
+    assert_eq!(
+        SseResponse::<BoxStream<'static, Update>>::name(),
+        "Sse_Update"
+    );
+}
+
+#[derive(UtoipaResponse)]
+#[response(status = "OK", content = "text/html", description = "dashboard")]
+#[rustfmt::skip]
+struct TestHtmlStreamResponse(HtmlStreamResponse::<BoxStream<'static, String>>);
+
+#[tokio::test]
+async fn test_html_stream_response() {
+    let res = HtmlStreamResponse::new(stream::pending::<String>())
+        .with_shell("<html><body>")
+        .into_response();
+    let mut chunks = res.into_body().into_data_stream();
+    assert_eq!(&chunks.next().await.unwrap().unwrap()[..], b"<html><body>");
+
+    let rows = stream::iter(["<p>1</p>", "<p>2</p>", "</body></html>"].map(String::from)).boxed();
+    let res: TestHtmlStreamResponse = HtmlStreamResponse::new(rows)
+        .with_shell("<html><body>")
+        .with_cookie("session=abc")
+        .into();
+    let res = res.into_response();
+    assert_eq!(
+        res.headers().get("content-type").unwrap(),
+        "text/html; charset=utf-8"
+    );
+    assert_eq!(res.headers().get("set-cookie").unwrap(), "session=abc");
+    let body = common::body_bytes(res).await;
+    assert_eq!(&body[..], b"<html><body><p>1</p><p>2</p></body></html>");
+
+    let responses = TestHtmlStreamResponse::responses();
+    let RefOr::T(response) = &responses["200"] else {
+        panic!("expected inline response");
+    };
+    let content = &response.content["text/html"];
+    assert_eq!(
+        serde_json::to_value(content.schema.as_ref().unwrap()).unwrap(),
+        serde_json::json!({"type": "string"})
+    );
+}
@@ -0,0 +1,60 @@
+#![cfg(feature = "askama")]
+
+mod common;
+
+use askama::Template;
+use axum::response::IntoResponse;
+use utoipa::openapi::RefOr;
+use utoipa::{IntoResponses, PartialSchema, ToSchema};
+
+use utoipa_helper::{
+    UtoipaResponse,
+    html_response::HtmlResponse,
+    template_response::{Rendered, TemplateError},
+};
+
+#[derive(Template)]
+#[template(source = "<h1>{{ title }}</h1>", ext = "html")]
+struct IndexTemplate<'a> {
+    title: &'a str,
+}
+
+#[derive(UtoipaResponse)]
+#[response(status = "OK", content = "text/html", description = "index page")]
+#[rustfmt::skip]
+struct IndexResponse(HtmlResponse::<Rendered<IndexTemplate<'static>>>);
+
+#[tokio::test]
+async fn test_template_response() {
+    async fn index() -> Result<IndexResponse, TemplateError> {
+        let template = IndexTemplate { title: "<Home>" };
+        Ok(HtmlResponse::from_template(&template)?.into())
+    }
+
+    let res = index().await.into_response();
+    assert_eq!(
+        res.headers().get("content-type").unwrap(),
+        "text/html; charset=utf-8"
+    );
+    let body = common::body_bytes(res).await;
+    assert_eq!(&body[..], b"<h1>&#60;Home&#62;</h1>");
+
+    assert_eq!(
+        serde_json::to_value(Rendered::<IndexTemplate>::schema()).unwrap(),
+        serde_json::json!({"type": "string", "x-template": "IndexTemplate"})
+    );
+    let responses = IndexResponse::responses();
+    assert!(matches!(&responses["200"], RefOr::T(_)));
+    assert_eq!(Rendered::<IndexTemplate>::name(), "Rendered_IndexTemplate");
+    assert!(TemplateError::responses().contains_key("500"));
+
+    let error = TemplateError(askama::Error::Custom("secret detail".into()));
+    let res = error.into_response();
+    assert_eq!(res.status(), 500);
+    assert_eq!(
+        res.headers().get("content-type").unwrap(),
+        "application/problem+json"
+    );
+    let body = common::body_bytes(res).await;
+    assert!(!String::from_utf8_lossy(&body).contains("secret detail"));
+}
@@ -9,9 +9,9 @@ use tokio::net::TcpListener;
 use utoipa::{IntoParams, OpenApi};
 use uuid::Uuid;
 
-use utoipa::{IntoResponses, PartialSchema, ToSchema};
+use utoipa::{PartialSchema, ToSchema};
 use utoipa_helper::{
-    UtoipaResponse, derive_utoipa_params, derive_utoipa_schema, derive_utoipa_test,
+    UtoipaError, UtoipaResponse, derive_utoipa_params, derive_utoipa_schema, derive_utoipa_test,
     html_response::HtmlResponse, json_response::JsonResponse,
 };
 
@@ -60,15 +60,15 @@ derive_utoipa_params!(Test01, Test1);
 struct TestResponse0(JsonResponse::<Test01>);
 
 #[allow(dead_code)]
-#[derive(Debug, IntoResponses, ToSchema)]
+#[derive(Debug, UtoipaError, ToSchema)]
 enum TestError {
-    #[response(status = NOT_FOUND)]
+    #[problem(status = NOT_FOUND, type = "https://example.com/problems/not-found")]
     NotFound,
-    #[response(status = INTERNAL_SERVER_ERROR)]
+    #[problem(status = INTERNAL_SERVER_ERROR)]
     InternalServerError,
-    #[response(status = BAD_REQUEST)]
+    #[problem(status = BAD_REQUEST, title = "Invalid Input")]
     BadRequest,
-    #[response(status = METHOD_NOT_ALLOWED)]
+    #[problem(status = 405)]
     MethodNotAllowed,
 }
 
@@ -104,8 +104,8 @@ async fn test_basic_example() {
     async fn test_json() -> TestJsonResponse {
         let test = TestJson {
             field: "test_field".into(),
-            id: Uuid::new_v4().into(),
-            value: Decimal::from_str_exact("1.234").unwrap().into(),
+            id: Uuid::new_v4(),
+            value: Decimal::from_str_exact("1.234").unwrap(),
         };
         JsonResponse::new(test).into()
     }
@@ -178,7 +178,7 @@ async fn test_basic_example() {
 
     let expected = include_str!("test_basic_example.json");
 
-    if &api != expected {
+    if api != expected {
         std::fs::write("./tests/test_basic_example.json", &api).unwrap();
     }
 
@@ -187,12 +187,6 @@ async fn test_basic_example() {
     task.abort();
 }
 
-impl axum::response::IntoResponse for TestError {
-    fn into_response(self) -> axum::response::Response {
-        format!("{self}").into_response()
-    }
-}
-
 #[test]
 fn test_derive_utoipa_test() {
     derive_utoipa_test!(Test0, Test1);
@@ -213,7 +207,7 @@ async fn test_api_spec() {
     let spec_json = serde_json::to_string_pretty(&spec).unwrap();
 
     let expected = include_str!("test_schema.json");
-    if &spec_json != expected {
+    if spec_json != expected {
         std::fs::write("./tests/test_schema.json", &spec_json).unwrap();
     }
     assert_eq!(&spec_json, expected);
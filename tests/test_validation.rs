@@ -0,0 +1,236 @@
+#![cfg(feature = "validation")]
+
+mod common;
+
+use axum::Router;
+use axum::body::Body;
+use axum::extract::Query;
+use axum::http::{Request, StatusCode};
+use axum::routing::{get, post};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use utoipa::{IntoResponses, ToSchema};
+
+use utoipa_helper::json_body::{JsonBody, JsonBodyRejection};
+use utoipa_helper::validation::{
+    FieldError, Validated, ValidatedRejection, ValidationError, validate,
+};
+
+#[derive(Serialize, Deserialize, ToSchema)]
+struct Address {
+    #[schema(pattern = "^[0-9]{5}$")]
+    zip: String,
+}
+
+#[derive(Serialize, Deserialize, ToSchema)]
+struct NewUser {
+    #[schema(min_length = 1, max_length = 8)]
+    name: String,
+    #[schema(minimum = 18, maximum = 150)]
+    age: u32,
+    #[schema(format = Email)]
+    email: String,
+    address: Option<Address>,
+    #[schema(min_items = 1)]
+    tags: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, ToSchema)]
+struct Search {
+    #[schema(minimum = 1, maximum = 100)]
+    limit: u32,
+    #[serde(default)]
+    #[schema(min_length = 1)]
+    sort: String,
+}
+
+#[derive(Serialize, Deserialize, ToSchema)]
+struct Price {
+    #[schema(multiple_of = 0.1)]
+    amount: f64,
+}
+
+#[derive(Serialize, Deserialize, ToSchema)]
+struct Profile {
+    nickname: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, ToSchema)]
+#[serde(untagged)]
+enum Quantity {
+    Count(u32),
+    Measure(f64),
+}
+
+#[derive(Serialize, Deserialize, ToSchema)]
+struct Coupon {
+    #[schema(pattern = "([0-9]")]
+    code: String,
+}
+
+fn user() -> NewUser {
+    NewUser {
+        name: "alice".into(),
+        age: 30,
+        email: "alice@example.com".into(),
+        address: Some(Address {
+            zip: "12345".into(),
+        }),
+        tags: vec!["a".into()],
+    }
+}
+
+fn error(path: &str, message: &str) -> FieldError {
+    FieldError {
+        path: path.into(),
+        message: message.into(),
+    }
+}
+
+#[test]
+fn test_validate() {
+    let value = |user: &NewUser| serde_json::to_value(user).unwrap();
+    assert!(validate::<NewUser>(&value(&user())).is_ok());
+
+    let invalid = NewUser {
+        name: String::new(),
+        age: 12,
+        email: "alice".into(),
+        address: Some(Address { zip: "1".into() }),
+        tags: Vec::new(),
+    };
+    let Err(ValidationError::Invalid(errors)) = validate::<NewUser>(&value(&invalid)) else {
+        panic!("expected field errors");
+    };
+    assert_eq!(
+        errors.errors,
+        vec![
+            error("address.zip", "must match pattern `^[0-9]{5}$`"),
+            error("age", "must be at least 18"),
+            error("email", "must be a valid email"),
+            error("name", "must be at least 1 characters"),
+            error("tags", "must have at least 1 items"),
+        ]
+    );
+
+    let no_address = NewUser {
+        address: None,
+        ..user()
+    };
+    assert!(validate::<NewUser>(&value(&no_address)).is_ok());
+
+    assert!(validate::<Price>(&json!({"amount": 0.3})).is_ok());
+    assert!(validate::<Price>(&json!({"amount": 12.7})).is_ok());
+    assert!(validate::<Price>(&json!({"amount": 0.35})).is_err());
+
+    let Err(ValidationError::Invalid(errors)) = validate::<Profile>(&json!({"nickname": 5})) else {
+        panic!("expected field errors");
+    };
+    assert_eq!(
+        errors.errors,
+        vec![error("nickname", "expected string or null")]
+    );
+
+    assert!(validate::<Quantity>(&json!(2.5)).is_ok());
+    let Err(ValidationError::Invalid(errors)) = validate::<Quantity>(&json!(3)) else {
+        panic!("expected field errors");
+    };
+    assert_eq!(
+        errors.errors,
+        vec![error(".", "matches more than one allowed schema")]
+    );
+
+    assert!(matches!(
+        validate::<Coupon>(&json!({"code": "A1"})),
+        Err(ValidationError::Schema(_))
+    ));
+}
+
+async fn create_user(Validated(JsonBody(user)): Validated<JsonBody<NewUser>>) -> String {
+    user.name
+}
+
+async fn search(Validated(Query(search)): Validated<Query<Search>>) -> String {
+    search.limit.to_string()
+}
+
+async fn redeem(Validated(JsonBody(coupon)): Validated<JsonBody<Coupon>>) -> String {
+    coupon.code
+}
+
+async fn send(req: Request<Body>) -> (StatusCode, serde_json::Value) {
+    let app = Router::new()
+        .route("/users", post(create_user))
+        .route("/search", get(search))
+        .route("/coupons", post(redeem));
+    common::send(app, req).await
+}
+
+fn post_json(uri: &str, body: &serde_json::Value) -> Request<Body> {
+    Request::post(uri)
+        .header("content-type", "application/json")
+        .body(Body::from(serde_json::to_vec(body).unwrap()))
+        .unwrap()
+}
+
+fn post_user(user: &NewUser) -> Request<Body> {
+    post_json("/users", &serde_json::to_value(user).unwrap())
+}
+
+#[tokio::test]
+async fn test_validated_extractors() {
+    let (status, _) = send(post_user(&user())).await;
+    assert_eq!(status, StatusCode::OK);
+
+    let (status, body) = send(post_user(&NewUser { age: 200, ..user() })).await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(
+        body["errors"],
+        serde_json::json!([{"path": "age", "message": "must be at most 150"}])
+    );
+
+    let mut absent_address = serde_json::to_value(user()).unwrap();
+    absent_address.as_object_mut().unwrap().remove("address");
+    let (status, _) = send(post_json("/users", &absent_address)).await;
+    assert_eq!(status, StatusCode::OK);
+
+    let mut null_age = serde_json::to_value(user()).unwrap();
+    null_age["age"] = serde_json::Value::Null;
+    let (status, body) = send(post_json("/users", &null_age)).await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(body["errors"][0]["path"], "age");
+
+    let (status, body) = send(post_json("/coupons", &json!({"code": "A1"}))).await;
+    assert_eq!(status, StatusCode::INTERNAL_SERVER_ERROR);
+    assert!(body.get("detail").is_none());
+
+    let get = |uri: &str| Request::get(uri).body(Body::empty()).unwrap();
+    let (status, _) = send(get("/search?limit=10")).await;
+    assert_eq!(status, StatusCode::OK);
+    let (status, body) = send(get("/search?limit=10&sort=")).await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(body["errors"][0]["path"], "sort");
+    let (status, body) = send(get("/search?limit=0")).await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(body["errors"][0]["path"], "limit");
+    let (status, body) = send(get("/search?limit=x")).await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(
+        body["errors"],
+        json!([{"path": "limit", "message": "expected integer"}])
+    );
+}
+
+#[test]
+fn test_validated_responses() {
+    let responses =
+        serde_json::to_value(ValidatedRejection::<JsonBodyRejection>::responses()).unwrap();
+    let content = &responses["422"]["content"]["application/problem+json"];
+    assert!(content["schema"]["allOf"][1]["properties"]["errors"].is_object());
+    assert_eq!(
+        content["examples"]["Validation"]["value"]["errors"][0]["path"],
+        "name"
+    );
+    assert!(responses["415"].is_object());
+    assert!(responses["500"].is_object());
+}
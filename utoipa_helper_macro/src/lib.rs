@@ -1,4 +1,5 @@
 use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
 use quote::quote;
 use syn::{
     spanned::Spanned, Data, DeriveInput, Expr, Fields, Lit, Meta, PathArguments, Token, Type,
@@ -11,8 +12,11 @@ pub fn derive_utoipa_response_fn(input: TokenStream) -> TokenStream {
     struct UtoipaResponse {
         description: Option<String>,
         content: Option<String>,
-        status: Option<String>,
+        status: Option<Status>,
         error: Option<String>,
+        etag: bool,
+        envelope: bool,
+        cache: Option<Expr>,
     }
     let mut utoipa_response = UtoipaResponse::default();
     let input: DeriveInput = syn::parse(input).expect("Failed to parse");
@@ -26,13 +30,25 @@ pub fn derive_utoipa_response_fn(input: TokenStream) -> TokenStream {
                     .parse_nested_meta(|meta| {
                         if let Some(ident) = meta.path.get_ident() {
                             let ident = ident.to_string();
-                            if let Expr::Lit(lit) = meta.value()?.parse::<Expr>()? {
+                            if !meta.input.peek(Token![=]) {
+                                match ident.as_str() {
+                                    "etag" => utoipa_response.etag = true,
+                                    "envelope" => utoipa_response.envelope = true,
+                                    id => panic!("{} is not a valid flag", id),
+                                }
+                                return Ok(());
+                            }
+                            let value = meta.value()?.parse::<Expr>()?;
+                            if ident == "status" {
+                                utoipa_response.status = Some(parse_status(&value));
+                            } else if ident == "cache" {
+                                utoipa_response.cache = Some(value);
+                            } else if let Expr::Lit(lit) = value {
                                 if let Lit::Str(lit) = lit.lit {
                                     let lit = Some(lit.value());
                                     match ident.as_str() {
                                         "description" => utoipa_response.description = lit,
                                         "content" => utoipa_response.content = lit,
-                                        "status" => utoipa_response.status = lit,
                                         "error" => utoipa_response.error = lit,
                                         id => panic!("{} is not a valid key", id),
                                     }
@@ -75,26 +91,27 @@ pub fn derive_utoipa_response_fn(input: TokenStream) -> TokenStream {
         Some("text/css") => Some(quote! {utoipa_helper::content_type_trait::ContentTypeCss}),
         Some("text/javascript") => Some(quote! {utoipa_helper::content_type_trait::ContentTypeJs}),
         Some("application/json") => Some(quote! {utoipa_helper::content_type_trait::ContentTypeJson}),
+        Some("application/problem+json") => Some(quote! {utoipa_helper::content_type_trait::ContentTypeProblemJson}),
+        Some("application/x-ndjson") => Some(quote! {utoipa_helper::content_type_trait::ContentTypeNdjson}),
+        Some("text/event-stream") => Some(quote! {utoipa_helper::content_type_trait::ContentTypeEventStream}),
+        Some("application/yaml") => Some(quote! {utoipa_helper::content_type_trait::ContentTypeYaml}),
+        Some("application/xml") => Some(quote! {utoipa_helper::content_type_trait::ContentTypeXml}),
+        Some("application/msgpack") => Some(quote! {utoipa_helper::content_type_trait::ContentTypeMsgpack}),
+        Some("application/hal+json") => Some(quote! {utoipa_helper::content_type_trait::ContentTypeHal}),
+        Some("application/vnd.api+json") => Some(quote! {utoipa_helper::content_type_trait::ContentTypeJsonApi}),
         Some(val) => panic!("{} is not a valid content type", val),
         None => None,
     };
-    let status = match utoipa_response.status.as_deref() {
-        Some("OK") => Some(quote! {utoipa_helper::status_code_trait::StatusCodeOk}),
-        Some("CREATED") => Some(quote! {utoipa_helper::status_code_trait::StatusCodeCreated}),
-        Some("NO_CONTENT") => Some(quote!(utoipa_helper::status_code_trait::StatusCodeNoContent)),
-        Some(s) => s
-            .parse::<u16>()
-            .ok()
-            .map(|c| quote!(utoipa_helper::status_code_trait::StatusCodeValue::<#c>)),
-        _ => None,
-    };
+    let status = utoipa_response.status.as_ref().map(Status::marker_tokens);
     let content_reply = if let Some(content) = &content {
         quote! {
             use utoipa_helper::content_type_trait::ContentTypeTrait;
-            res.headers_mut().insert(
-                axum::http::header::CONTENT_TYPE ,
-                axum::http::HeaderValue::from_static( #content::content_type_header() )
-            );
+            if !conditional {
+                res.headers_mut().insert(
+                    axum::http::header::CONTENT_TYPE ,
+                    axum::http::HeaderValue::from_static( #content::content_type_header() )
+                );
+            }
         }
     } else {
         quote! {}
@@ -102,7 +119,40 @@ pub fn derive_utoipa_response_fn(input: TokenStream) -> TokenStream {
     let status_reply = if let Some(status) = &status {
         quote! {
             use utoipa_helper::status_code_trait::StatusCodeTrait;
-            *res.status_mut() = #status::status_code();
+            if !conditional {
+                *res.status_mut() = #status::resolve(
+                    utoipa_helper::status_code_trait::explicit_status(&res)
+                );
+            }
+        }
+    } else {
+        quote! {}
+    };
+    let cache_reply = if let Some(cache) = &utoipa_response.cache {
+        quote! {
+            if !res.headers().contains_key(axum::http::header::CACHE_CONTROL) {
+                let cache_policy: utoipa_helper::cache_policy::CachePolicy = #cache;
+                cache_policy.apply(res.headers_mut());
+            }
+        }
+    } else {
+        quote! {}
+    };
+    let inner_response = if utoipa_response.envelope {
+        quote! {
+            utoipa_helper::json_response::JsonResponse::new(
+                utoipa_helper::envelope::Envelope::<#inner_type>::new(self.0)
+            ).into_response()
+        }
+    } else {
+        quote! {self.0.into_response()}
+    };
+    let conditional = if content.is_some() || status.is_some() {
+        quote! {
+            let conditional = matches!(
+                res.status(),
+                axum::http::StatusCode::NOT_MODIFIED | axum::http::StatusCode::PRECONDITION_FAILED
+            );
         }
     } else {
         quote! {}
@@ -110,9 +160,12 @@ pub fn derive_utoipa_response_fn(input: TokenStream) -> TokenStream {
     let axum_into_response_impl = quote! {
         impl axum::response::IntoResponse for #ident {
             fn into_response(self) -> axum::response::Response {
-                let mut res = self.0.into_response();
+                let mut res = #inner_response;
+                #conditional
                 #content_reply
                 #status_reply
+                #cache_reply
+                utoipa_helper::empty_response::strip_forbidden_body(&mut res);
                 res
             }
         }
@@ -135,23 +188,61 @@ pub fn derive_utoipa_response_fn(input: TokenStream) -> TokenStream {
     let status_response_entity = if let Some(status) = &status {
         quote! {
             use utoipa_helper::status_code_trait::StatusCodeTrait;
-            code = #status::status_code().as_u16().to_string().into();
+            code = #status::response_key();
+        }
+    } else {
+        quote! {}
+    };
+    let allows_body = if let Some(status) = &status {
+        quote! {<#status as utoipa_helper::status_code_trait::StatusCodeTrait>::allows_body()}
+    } else {
+        quote! {true}
+    };
+    let etag_response_entity = if utoipa_response.etag {
+        quote! {
+            resp = resp.header("ETag", utoipa_helper::etag::etag_header());
+            responses = responses.response(
+                "304",
+                utoipa::openapi::ResponseBuilder::new()
+                    .description("Not modified")
+                    .header("ETag", utoipa_helper::etag::etag_header()),
+            );
         }
     } else {
         quote! {}
     };
+    let cache_response_entity = if let Some(cache) = &utoipa_response.cache {
+        quote! {
+            let cache_policy: utoipa_helper::cache_policy::CachePolicy = #cache;
+            resp = cache_policy.document(resp);
+        }
+    } else {
+        quote! {}
+    };
+    let (default_content_type, inner_schema) = if utoipa_response.envelope {
+        (
+            "application/json",
+            quote! {utoipa_helper::envelope::Envelope::<#inner_type>::schema()},
+        )
+    } else {
+        ("text/html", quote! {#inner_type::schema()})
+    };
     let utoipa_into_responses_impl = quote! {
         impl utoipa::IntoResponses for #ident {
             fn responses() -> std::collections::BTreeMap<String, utoipa::openapi::RefOr<utoipa::openapi::Response>> {
                 let mut responses = utoipa::openapi::ResponsesBuilder::new();
                 let mut resp = utoipa::openapi::ResponseBuilder::new();
                 let mut code = std::borrow::Cow::Borrowed("200");
-                let mut content_type = std::borrow::Cow::Borrowed("text/html");
+                let mut content_type = std::borrow::Cow::Borrowed(#default_content_type);
                 #status_response_entity
                 #content_response_entity
-                let content = utoipa::openapi::content::ContentBuilder::new().schema(Some(#inner_type::schema())).build();
-                resp = resp.content(content_type, content);    
+                if #allows_body {
+                    let content = utoipa::openapi::content::ContentBuilder::new().schema(Some(#inner_schema)).build();
+                    resp = resp.content(content_type, content);
+                }
                 #description_response_entity
+                #etag_response_entity
+                #cache_response_entity
                 responses.response(code, resp).build().into()
             }
         }
@@ -164,3 +255,351 @@ pub fn derive_utoipa_response_fn(input: TokenStream) -> TokenStream {
     tokens.into()
 }
 
+#[derive(Debug)]
+enum Status {
+    Code(u16),
+    Constant(String),
+    Range(u16),
+    Default,
+}
+
+fn parse_status(expr: &Expr) -> Status {
+    let key = match expr {
+        Expr::Path(path) => path
+            .path
+            .segments
+            .last()
+            .expect("status must be a StatusCode constant")
+            .ident
+            .to_string(),
+        Expr::Lit(lit) => match &lit.lit {
+            Lit::Int(code) => code.base10_digits().to_string(),
+            Lit::Str(code) => code.value(),
+            _ => panic!("status must be a StatusCode constant, a number or a range"),
+        },
+        _ => panic!("status must be a StatusCode constant, a number or a range"),
+    };
+    if key == "default" {
+        return Status::Default;
+    }
+    if key.chars().all(|c| c.is_ascii_digit()) {
+        return match key.parse::<u16>() {
+            Ok(code) if (100..1000).contains(&code) => Status::Code(code),
+            _ => panic!("{} is not a valid status code", key),
+        };
+    }
+    let range = key
+        .strip_suffix("XX")
+        .or_else(|| key.strip_suffix("xx"))
+        .and_then(|class| class.parse::<u16>().ok())
+        .filter(|class| (1..=5).contains(class));
+    if let Some(class) = range {
+        return Status::Range(class);
+    }
+    if !key.chars().all(|c| c.is_ascii_uppercase() || c.is_ascii_digit() || c == '_') {
+        panic!("{} is not a valid status", key);
+    }
+    Status::Constant(key)
+}
+
+impl Status {
+    fn marker_tokens(&self) -> TokenStream2 {
+        match self {
+            Self::Constant(key) if key == "OK" => {
+                quote! {utoipa_helper::status_code_trait::StatusCodeOk}
+            }
+            Self::Constant(key) if key == "CREATED" => {
+                quote! {utoipa_helper::status_code_trait::StatusCodeCreated}
+            }
+            Self::Constant(key) if key == "NO_CONTENT" => {
+                quote! {utoipa_helper::status_code_trait::StatusCodeNoContent}
+            }
+            Self::Default => quote! {utoipa_helper::status_code_trait::StatusCodeDefault},
+            Self::Code(code) => {
+                quote! {utoipa_helper::status_code_trait::StatusCodeValue::<#code>}
+            }
+            Self::Range(class) => {
+                quote! {utoipa_helper::status_code_trait::StatusCodeRange::<#class>}
+            }
+            Self::Constant(key) => {
+                let ident = syn::Ident::new(key, proc_macro2::Span::call_site());
+                quote! {
+                    utoipa_helper::status_code_trait::StatusCodeValue::<
+                        { axum::http::StatusCode::#ident.as_u16() }
+                    >
+                }
+            }
+        }
+    }
+
+    fn code_tokens(&self) -> TokenStream2 {
+        match self {
+            Self::Code(code) => quote! {
+                match axum::http::StatusCode::from_u16(#code) {
+                    Ok(status) => status,
+                    Err(_) => unreachable!(),
+                }
+            },
+            Self::Constant(key) => {
+                let ident = syn::Ident::new(key, proc_macro2::Span::call_site());
+                quote! {axum::http::StatusCode::#ident}
+            }
+            Self::Range(class) => panic!("status range {}XX has no runtime status code", class),
+            Self::Default => panic!("status default has no runtime status code"),
+        }
+    }
+}
+
+fn lit_str_value(expr: &Expr) -> String {
+    if let Expr::Lit(lit) = expr {
+        if let Lit::Str(lit) = &lit.lit {
+            return lit.value();
+        }
+    }
+    panic!("expected a string literal");
+}
+
+#[proc_macro_derive(UtoipaError, attributes(problem))]
+pub fn derive_utoipa_error_fn(input: TokenStream) -> TokenStream {
+    let input: DeriveInput = syn::parse(input).expect("Failed to parse");
+    let DeriveInput { ident, data, .. } = input;
+    let Data::Enum(data_enum) = data else {
+        panic!("UtoipaError can only be derived for enums");
+    };
+    let mut match_arms = Vec::new();
+    let mut problem_variants = Vec::new();
+    for variant in &data_enum.variants {
+        let mut status = None;
+        let mut problem_type = None;
+        let mut title = None;
+        for attr in &variant.attrs {
+            if attr.path().is_ident("problem") {
+                attr.parse_nested_meta(|meta| {
+                    let expr: Expr = meta.value()?.parse()?;
+                    if meta.path.is_ident("status") {
+                        status = Some(parse_status(&expr).code_tokens());
+                    } else if meta.path.is_ident("type") {
+                        problem_type = Some(lit_str_value(&expr));
+                    } else if meta.path.is_ident("title") {
+                        title = Some(lit_str_value(&expr));
+                    } else {
+                        panic!("{} is not a valid key", meta.path.get_ident().unwrap());
+                    }
+                    Ok(())
+                })
+                .map_err(|e| panic!("encountered error {}", e))
+                .unwrap();
+            }
+        }
+        let status =
+            status.unwrap_or_else(|| quote! {axum::http::StatusCode::INTERNAL_SERVER_ERROR});
+        let problem_type = problem_type.unwrap_or_else(|| "about:blank".into());
+        let title = match title {
+            Some(title) => quote! {Some(#title)},
+            None => quote! {None},
+        };
+        let variant_ident = &variant.ident;
+        let pattern = match &variant.fields {
+            Fields::Named(_) => quote! {Self::#variant_ident { .. }},
+            Fields::Unnamed(_) => quote! {Self::#variant_ident(..)},
+            Fields::Unit => quote! {Self::#variant_ident},
+        };
+        match_arms.push(quote! {
+            #pattern => (#status, #problem_type, #title),
+        });
+        problem_variants.push(quote! {
+            utoipa_helper::problem_details::ProblemVariant {
+                name: stringify!(#variant_ident),
+                status: #status,
+                problem_type: #problem_type,
+                title: #title,
+            },
+        });
+    }
+    let tokens = quote! {
+        impl utoipa_helper::problem_details::IntoProblemDetails for #ident {
+            fn problem_details(&self) -> utoipa_helper::problem_details::ProblemDetails {
+                let (status, problem_type, title): (axum::http::StatusCode, &'static str, Option<&'static str>) = match self {
+                    #(#match_arms)*
+                };
+                let mut problem = utoipa_helper::problem_details::ProblemDetails::new(status)
+                    .with_type(problem_type);
+                if !status.is_server_error() {
+                    problem = problem.with_detail(self.to_string());
+                }
+                if let Some(title) = title {
+                    problem = problem.with_title(title);
+                }
+                problem
+            }
+        }
+
+        impl axum::response::IntoResponse for #ident {
+            fn into_response(self) -> axum::response::Response {
+                use utoipa_helper::problem_details::IntoProblemDetails;
+                self.problem_details().into_response()
+            }
+        }
+
+        impl utoipa::IntoResponses for #ident {
+            fn responses() -> std::collections::BTreeMap<String, utoipa::openapi::RefOr<utoipa::openapi::Response>> {
+                utoipa_helper::problem_details::problem_responses(&[
+                    #(#problem_variants)*
+                ])
+            }
+        }
+    };
+    tokens.into()
+}
+
+fn is_text_type(ty: &Type) -> bool {
+    match ty {
+        Type::Reference(reference) => is_text_type(&reference.elem),
+        Type::Path(typath) => typath
+            .path
+            .segments
+            .last()
+            .is_some_and(|segment| ["String", "str", "char"].contains(&segment.ident.to_string().as_str())),
+        _ => false,
+    }
+}
+
+fn serde_rename_all(attrs: &[syn::Attribute], key: &str) -> Option<syn::LitStr> {
+    let mut value = None;
+    for attr in attrs {
+        if attr.path().is_ident("serde") {
+            attr.parse_nested_meta(|meta| {
+                if meta.input.peek(syn::token::Paren) {
+                    meta.input.parse::<proc_macro2::Group>()?;
+                } else if meta.input.peek(Token![=]) {
+                    let expr: Expr = meta.value()?.parse()?;
+                    if meta.path.is_ident(key) {
+                        if let Expr::Lit(syn::ExprLit { lit: Lit::Str(lit), .. }) = expr {
+                            value = Some(lit);
+                        }
+                    }
+                }
+                Ok(())
+            })
+            .map_err(|e| panic!("encountered error {}", e))
+            .unwrap();
+        }
+    }
+    value
+}
+
+#[proc_macro_derive(UtoipaIntoResponse, attributes(response, serde))]
+pub fn derive_utoipa_into_response_fn(input: TokenStream) -> TokenStream {
+    let input: DeriveInput = syn::parse(input).expect("Failed to parse");
+    let DeriveInput {
+        attrs, ident, data, ..
+    } = input;
+    let Data::Enum(data_enum) = data else {
+        panic!("UtoipaIntoResponse can only be derived for enums");
+    };
+    let rename_all_fields = serde_rename_all(&attrs, "rename_all_fields");
+    let mut match_arms = Vec::new();
+    for variant in &data_enum.variants {
+        let mut status = None;
+        let mut content_type = None;
+        for attr in &variant.attrs {
+            if attr.path().is_ident("response") {
+                attr.parse_nested_meta(|meta| {
+                    if meta.input.peek(syn::token::Paren) {
+                        meta.input.parse::<proc_macro2::Group>()?;
+                        return Ok(());
+                    }
+                    let expr: Expr = meta.value()?.parse()?;
+                    if meta.path.is_ident("status") {
+                        status = Some(parse_status(&expr).code_tokens());
+                    } else if meta.path.is_ident("content_type") || meta.path.is_ident("content") {
+                        content_type = Some(lit_str_value(&expr));
+                    }
+                    Ok(())
+                })
+                .map_err(|e| panic!("encountered error {}", e))
+                .unwrap();
+            }
+        }
+        let variant_ident = &variant.ident;
+        let status = status.unwrap_or_else(|| {
+            panic!("{} is missing #[response(status = ...)]", variant_ident)
+        });
+        let content_header = content_type.as_ref().map(|content_type| {
+            quote! {
+                res.headers_mut().insert(
+                    axum::http::header::CONTENT_TYPE,
+                    axum::http::HeaderValue::from_static(#content_type),
+                );
+            }
+        });
+        let is_json = content_type
+            .as_deref()
+            .map(|c| c == "application/json" || c.ends_with("+json"));
+        let arm = match &variant.fields {
+            Fields::Unit => quote! {
+                Self::#variant_ident => {
+                    let mut res = #status.into_response();
+                    #content_header
+                    res
+                }
+            },
+            Fields::Unnamed(fields) => {
+                if fields.unnamed.len() != 1 {
+                    panic!("{} must carry exactly one body type", variant_ident);
+                }
+                let body_type = &fields.unnamed.first().unwrap().ty;
+                let res = if is_json.unwrap_or(!is_text_type(body_type)) {
+                    quote! {utoipa_helper::variant_response::json_response(#status, &body)}
+                } else {
+                    quote! {(#status, body).into_response()}
+                };
+                quote! {
+                    Self::#variant_ident(body) => {
+                        let mut res = #res;
+                        #content_header
+                        res
+                    }
+                }
+            }
+            Fields::Named(fields) => {
+                let names: Vec<_> = fields.named.iter().filter_map(|f| f.ident.as_ref()).collect();
+                let field_defs = fields.named.iter().map(|field| {
+                    let serde_attrs = field.attrs.iter().filter(|attr| attr.path().is_ident("serde"));
+                    let name = &field.ident;
+                    let ty = &field.ty;
+                    quote! {#(#serde_attrs)* #name: #ty}
+                });
+                let rename_all = serde_rename_all(&variant.attrs, "rename_all")
+                    .or_else(|| rename_all_fields.clone())
+                    .map(|rename_all| quote! {#[serde(rename_all = #rename_all)]});
+                quote! {
+                    Self::#variant_ident { #(#names),* } => {
+                        #[derive(utoipa_helper::variant_response::serde::Serialize)]
+                        #[serde(crate = "utoipa_helper::variant_response::serde")]
+                        #rename_all
+                        struct Body {
+                            #(#field_defs),*
+                        }
+                        let body = Body { #(#names),* };
+                        let mut res = utoipa_helper::variant_response::json_response(#status, &body);
+                        #content_header
+                        res
+                    }
+                }
+            }
+        };
+        match_arms.push(arm);
+    }
+    let tokens = quote! {
+        impl axum::response::IntoResponse for #ident {
+            fn into_response(self) -> axum::response::Response {
+                use axum::response::IntoResponse;
+                match self {
+                    #(#match_arms)*
+                }
+            }
+        }
+    };
+    tokens.into()
+}